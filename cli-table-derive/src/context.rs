@@ -1,20 +1,43 @@
 mod container;
 mod fields;
+mod variants;
 
-use syn::{DeriveInput, Result};
+use syn::{Data as SynData, DeriveInput, Error, Result};
 
-use self::{container::Container, fields::Fields};
+pub use self::{
+    container::Container,
+    fields::{Field, Fields},
+    variants::Variants,
+};
 
 pub struct Context<'a> {
     pub container: Container<'a>,
-    pub fields: Fields,
+    pub data: Data,
+}
+
+/// Fields of a struct or variants of an enum
+pub enum Data {
+    Struct(Fields),
+    Enum(Variants),
 }
 
 impl<'a> Context<'a> {
     pub fn new(input: &'a DeriveInput) -> Result<Self> {
         let container = Container::new(input)?;
-        let fields = Fields::new(input)?;
 
-        Ok(Self { container, fields })
+        let data = match input.data {
+            SynData::Struct(ref data_struct) => {
+                Data::Struct(Fields::new(&data_struct.fields, &container)?)
+            }
+            SynData::Enum(ref data_enum) => Data::Enum(Variants::new(data_enum, &container)?),
+            SynData::Union(_) => {
+                return Err(Error::new_spanned(
+                    input,
+                    "`cli_table` derive macros can only be used on structs and enums",
+                ));
+            }
+        };
+
+        Ok(Self { container, data })
     }
 }
@@ -1,38 +1,117 @@
+use proc_macro2::Span;
 use quote::quote;
-use syn::{DeriveInput, Error, Ident, Lit, Path, Result};
+use syn::{DeriveInput, Error, Expr, Ident, Lit, LitBool, LitStr, Path, Result};
 
 use crate::utils::get_attributes;
 
 pub struct Container<'a> {
     pub crate_name: Path,
     pub name: &'a Ident,
+    pub index: Option<usize>,
+    /// Title of the first column of enums containing names of variants
+    pub kind_title: Option<LitStr>,
+    pub rename_all: Option<RenameRule>,
+    pub justify: Option<Expr>,
+    pub align: Option<Expr>,
+    pub color: Option<Expr>,
+    pub title_bold: Option<LitBool>,
+    pub title_color: Option<Expr>,
+    pub title_background: Option<Expr>,
+    pub border: Option<Expr>,
+    pub separator: Option<Expr>,
+    /// Whether doc comments of fields (including fields of enum variants) are used as titles and descriptions of their
+    /// columns
+    pub doc_comments: bool,
 }
 
 impl<'a> Container<'a> {
     pub fn new(input: &'a DeriveInput) -> Result<Self> {
         let container_attributes = get_attributes(&input.attrs)?;
 
-        let mut crate_name = None;
+        let mut container_builder = Container::builder(&input.ident);
 
         for (key, value) in container_attributes {
             if key.is_ident("crate") {
-                crate_name = Some(match value {
+                container_builder.crate_name(match value {
                     Lit::Str(lit_str) => lit_str.parse::<Path>(),
                     bad => Err(Error::new_spanned(
                         bad,
                         "Invalid value for #[table(crate = \"crate_path\")]",
                     )),
                 }?);
+            } else if key.is_ident("index") {
+                let index = match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool.value.then_some(1)),
+                    Lit::Int(lit_int) => lit_int.base10_parse::<usize>().map(Some),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(index)] or #[table(index = <usize>)]",
+                    )),
+                }?;
+
+                if let Some(index) = index {
+                    container_builder.index(index);
+                }
+            } else if key.is_ident("kind_title") {
+                container_builder.kind_title(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(kind_title = \"title\")]",
+                    )),
+                }?);
+            } else if key.is_ident("rename_all") {
+                container_builder.rename_all(match value {
+                    Lit::Str(lit_str) => RenameRule::from_lit(&lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(rename_all = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("justify") {
+                container_builder.justify(parse_expr(value, "justify")?);
+            } else if key.is_ident("align") {
+                container_builder.align(parse_expr(value, "align")?);
+            } else if key.is_ident("color") {
+                container_builder.color(parse_expr(value, "color")?);
+            } else if key.is_ident("title_bold") {
+                container_builder.title_bold(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(title_bold)]",
+                    )),
+                }?);
+            } else if key.is_ident("title_color") {
+                container_builder.title_color(parse_expr(value, "title_color")?);
+            } else if key.is_ident("title_background") {
+                container_builder.title_background(parse_expr(value, "title_background")?);
+            } else if key.is_ident("border") {
+                container_builder.border(parse_expr(value, "border")?);
+            } else if key.is_ident("separator") {
+                container_builder.separator(parse_expr(value, "separator")?);
+            } else if key.is_ident("doc_comments") {
+                container_builder.doc_comments(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool.value),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(doc_comments)]",
+                    )),
+                }?);
             }
         }
 
-        let mut container_builder = Container::builder(&input.ident);
+        Ok(container_builder.build())
+    }
 
-        if let Some(crate_name) = crate_name {
-            container_builder.crate_name(crate_name);
+    /// Returns the title of the first column of enums containing names of variants (`Kind` converted using
+    /// `rename_all` rule by default)
+    pub fn kind_column_title(&self) -> LitStr {
+        match (&self.kind_title, self.rename_all) {
+            (Some(kind_title), _) => kind_title.clone(),
+            (None, Some(rule)) => LitStr::new(&rule.apply("Kind"), Span::call_site()),
+            (None, None) => LitStr::new("Kind", Span::call_site()),
         }
-
-        Ok(container_builder.build())
     }
 
     fn builder(name: &'a Ident) -> ContainerBuilder<'a> {
@@ -40,9 +119,131 @@ impl<'a> Container<'a> {
     }
 }
 
+/// Parses the value of an attribute containing an expression (e.g., `#[table(color = "Color::Red")]`)
+fn parse_expr(value: Lit, key: &str) -> Result<Expr> {
+    match value {
+        Lit::Str(lit_str) => lit_str.parse::<Expr>(),
+        bad => Err(Error::new_spanned(
+            bad,
+            format!("Invalid value for #[table({} = \"value\")]", key),
+        )),
+    }
+}
+
+/// Case conversion applied to the default titles of columns (i.e., names of fields and variants)
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    /// `first name`
+    Lower,
+    /// `FIRST NAME`
+    Upper,
+    /// `First Name`
+    Title,
+    /// `First name`
+    Sentence,
+    /// `first_name`
+    Snake,
+    /// `first-name`
+    Kebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit_str: &LitStr) -> Result<Self> {
+        match lit_str.value().as_str() {
+            "lower" => Ok(Self::Lower),
+            "UPPER" => Ok(Self::Upper),
+            "Title Case" => Ok(Self::Title),
+            "Sentence case" => Ok(Self::Sentence),
+            "snake_case" => Ok(Self::Snake),
+            "kebab-case" => Ok(Self::Kebab),
+            _ => Err(Error::new_spanned(
+                lit_str,
+                "Invalid value for #[table(rename_all = \"value\")]. Expected one of: \"lower\", \"UPPER\", \
+                 \"Title Case\", \"Sentence case\", \"snake_case\", \"kebab-case\"",
+            )),
+        }
+    }
+
+    /// Converts the name of a field (`snake_case`) or variant (`PascalCase`) using current rule
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name.trim_start_matches("r#"));
+
+        match self {
+            Self::Lower => words.join(" "),
+            Self::Upper => words.join(" ").to_uppercase(),
+            Self::Title => words
+                .iter()
+                .map(|word| capitalize(word))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::Sentence => capitalize(&words.join(" ")),
+            Self::Snake => words.join("_"),
+            Self::Kebab => words.join("-"),
+        }
+    }
+}
+
+/// Splits a name into lowercase words at underscores and at the start of each uppercase word
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut chars = name.chars().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+        } else {
+            // A new word starts at an uppercase character following a lowercase character or digit (`firstName`), or
+            // at the last uppercase character of an acronym followed by a lowercase character (`HTTPServer`)
+            let starts_word = c.is_uppercase()
+                && previous.is_some_and(|previous| {
+                    !previous.is_uppercase() || chars.peek().is_some_and(|next| next.is_lowercase())
+                });
+
+            if starts_word && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+
+            word.extend(c.to_lowercase());
+        }
+
+        previous = Some(c).filter(|c| *c != '_');
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 struct ContainerBuilder<'a> {
     crate_name: Option<Path>,
     name: &'a Ident,
+    index: Option<usize>,
+    kind_title: Option<LitStr>,
+    rename_all: Option<RenameRule>,
+    justify: Option<Expr>,
+    align: Option<Expr>,
+    color: Option<Expr>,
+    title_bold: Option<LitBool>,
+    title_color: Option<Expr>,
+    title_background: Option<Expr>,
+    border: Option<Expr>,
+    separator: Option<Expr>,
+    doc_comments: bool,
 }
 
 impl<'a> ContainerBuilder<'a> {
@@ -50,6 +251,18 @@ impl<'a> ContainerBuilder<'a> {
         Self {
             crate_name: None,
             name,
+            index: None,
+            kind_title: None,
+            rename_all: None,
+            justify: None,
+            align: None,
+            color: None,
+            title_bold: None,
+            title_color: None,
+            title_background: None,
+            border: None,
+            separator: None,
+            doc_comments: false,
         }
     }
 
@@ -58,12 +271,107 @@ impl<'a> ContainerBuilder<'a> {
         self
     }
 
+    pub fn index(&mut self, index: usize) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub fn kind_title(&mut self, kind_title: LitStr) -> &mut Self {
+        self.kind_title = Some(kind_title);
+        self
+    }
+
+    pub fn rename_all(&mut self, rename_all: RenameRule) -> &mut Self {
+        self.rename_all = Some(rename_all);
+        self
+    }
+
+    pub fn justify(&mut self, justify: Expr) -> &mut Self {
+        self.justify = Some(justify);
+        self
+    }
+
+    pub fn align(&mut self, align: Expr) -> &mut Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn color(&mut self, color: Expr) -> &mut Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn title_bold(&mut self, title_bold: LitBool) -> &mut Self {
+        self.title_bold = Some(title_bold);
+        self
+    }
+
+    pub fn title_color(&mut self, title_color: Expr) -> &mut Self {
+        self.title_color = Some(title_color);
+        self
+    }
+
+    pub fn title_background(&mut self, title_background: Expr) -> &mut Self {
+        self.title_background = Some(title_background);
+        self
+    }
+
+    pub fn border(&mut self, border: Expr) -> &mut Self {
+        self.border = Some(border);
+        self
+    }
+
+    pub fn separator(&mut self, separator: Expr) -> &mut Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    pub fn doc_comments(&mut self, doc_comments: bool) -> &mut Self {
+        self.doc_comments = doc_comments;
+        self
+    }
+
     pub fn build(self) -> Container<'a> {
         Container {
             crate_name: self
                 .crate_name
                 .unwrap_or_else(|| syn::parse2(quote!(::cli_table)).unwrap()),
             name: self.name,
+            index: self.index,
+            kind_title: self.kind_title,
+            rename_all: self.rename_all,
+            justify: self.justify,
+            align: self.align,
+            color: self.color,
+            title_bold: self.title_bold,
+            title_color: self.title_color,
+            title_background: self.title_background,
+            border: self.border,
+            separator: self.separator,
+            doc_comments: self.doc_comments,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(vec!["http", "server"], split_words("HTTPServer"));
+        assert_eq!(vec!["first", "name"], split_words("firstName"));
+        assert_eq!(vec!["field", "2"], split_words("field_2"));
+        assert_eq!(vec!["user", "id"], split_words("__user__id"));
+    }
+
+    #[test]
+    fn test_rename_rule() {
+        assert_eq!("User Id", RenameRule::Title.apply("user_id"));
+        assert_eq!("Http server", RenameRule::Sentence.apply("HTTPServer"));
+        assert_eq!("TYPE", RenameRule::Upper.apply("r#type"));
+        assert_eq!("field-2", RenameRule::Kebab.apply("field_2"));
+        assert_eq!("http_server", RenameRule::Snake.apply("HTTPServer"));
+        assert_eq!("kind", RenameRule::Lower.apply("Kind"));
+    }
+}
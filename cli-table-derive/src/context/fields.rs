@@ -1,45 +1,49 @@
 use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{
-    Data, DeriveInput, Error, Expr, Field as SynField, Fields as SynFields, Ident, Index, Lit,
-    LitBool, LitStr, Result, spanned::Spanned,
+    Error, Expr, Field as SynField, Fields as SynFields, GenericArgument, Index, Lit, LitBool,
+    LitStr, PathArguments, Result, Type, spanned::Spanned,
 };
 
-use crate::utils::get_attributes;
+use crate::{
+    context::container::Container,
+    utils::{get_attributes, get_doc},
+};
 
 pub struct Fields {
     fields: Vec<Field>,
+    pub children: Option<TokenStream>,
 }
 
 impl Fields {
-    pub fn new(input: &DeriveInput) -> Result<Self> {
-        match input.data {
-            Data::Struct(ref data_struct) => Self::from_fields(&data_struct.fields),
-            _ => Err(Error::new_spanned(
-                input,
-                "`cli_table` derive macros can only be used on structs",
-            )),
-        }
-    }
-
-    pub fn into_iter(self) -> impl Iterator<Item = Field> {
-        self.fields.into_iter()
-    }
-
-    fn from_fields(syn_fields: &SynFields) -> Result<Self> {
+    pub fn new(syn_fields: &SynFields, container: &Container<'_>) -> Result<Self> {
         let mut fields = Vec::new();
+        let mut children = None;
 
         for (index, syn_field) in syn_fields.into_iter().enumerate() {
-            let field = Field::new(syn_field, index)?;
+            let field = Field::new(syn_field, index, container)?;
 
             if let Some(field) = field {
-                fields.push(field);
+                if !field.children {
+                    fields.push(field);
+                } else if children.is_none() {
+                    children = Some(field.ident);
+                } else {
+                    return Err(Error::new(
+                        field.span,
+                        "#[table(children)] can only be used on one field",
+                    ));
+                }
             }
         }
 
-        fields.sort_by(|left, right| left.order.cmp(&right.order));
+        fields.sort_by_key(|field| field.order);
+
+        Ok(Fields { fields, children })
+    }
 
-        Ok(Fields { fields })
+    pub fn iter(&self) -> impl Iterator<Item = &Field> {
+        self.fields.iter()
     }
 }
 
@@ -51,13 +55,42 @@ pub struct Field {
     pub color: Option<Expr>,
     pub bold: Option<LitBool>,
     pub order: usize,
-    pub display_fn: Option<Ident>,
-    pub customize_fn: Option<Ident>,
+    pub display_fn: Option<Expr>,
+    pub format: Option<LitStr>,
+    pub customize_fn: Option<Expr>,
+    pub style_fn: Option<Expr>,
+    pub skip_if: Option<Expr>,
+    pub collection: Option<Collection>,
+    /// Whether the field is an `Option` whose `None` values are displayed as missing values
+    pub optional: bool,
+    pub placeholder: Option<LitStr>,
+    /// Whether the field has a numeric type (possibly wrapped in a reference or an `Option`) and is displayed as it is
+    /// (i.e., without `display_fn` or `format`)
+    pub numeric: bool,
+    /// Doc comment of the field (only when `doc_comments` container attribute is set)
+    pub description: Option<String>,
+    pub children: bool,
+    pub flatten: Option<Flatten>,
     pub span: Span,
 }
 
+/// Configuration for displaying the items of a collection
+pub struct Collection {
+    /// Separator between items (`None` for displaying items on separate lines)
+    pub join: Option<LitStr>,
+    pub max_items: Option<usize>,
+}
+
+/// Configuration of a field whose columns are spliced into the table of its parent
+pub struct Flatten {
+    pub ty: Type,
+    pub prefix: Option<LitStr>,
+    pub group: Option<LitStr>,
+}
+
 impl Field {
-    pub fn new(field: &SynField, index: usize) -> Result<Option<Self>> {
+    /// Creates a field from its attributes, falling back to the defaults in container attributes (if any)
+    pub fn new(field: &SynField, index: usize, container: &Container<'_>) -> Result<Option<Self>> {
         let ident = field
             .ident
             .as_ref()
@@ -72,8 +105,19 @@ impl Field {
         let mut bold = None;
         let mut order = None;
         let mut display_fn = None;
+        let mut format = None;
         let mut customize_fn = None;
+        let mut style_fn = None;
+        let mut skip_if = None;
+        let mut placeholder = None;
+        let mut join = None;
+        let mut lines = None;
+        let mut max_items = None;
         let mut skip = None;
+        let mut children = None;
+        let mut flatten = None;
+        let mut prefix = None;
+        let mut group = None;
 
         let field_attributes = get_attributes(&field.attrs)?;
 
@@ -125,7 +169,7 @@ impl Field {
                 }?);
             } else if key.is_ident("display_fn") {
                 display_fn = Some(match value {
-                    Lit::Str(lit_str) => lit_str.parse::<Ident>(),
+                    Lit::Str(lit_str) => lit_str.parse::<Expr>(),
                     bad => Err(Error::new_spanned(
                         bad,
                         "Invalid value for #[table(display_fn = \"value\")]",
@@ -133,10 +177,63 @@ impl Field {
                 }?);
             } else if key.is_ident("customize_fn") {
                 customize_fn = Some(match value {
-                    Lit::Str(lit_str) => lit_str.parse::<Ident>(),
+                    Lit::Str(lit_str) => lit_str.parse::<Expr>(),
                     bad => Err(Error::new_spanned(
                         bad,
-                        "Invalid value for #[table(display_fn = \"value\")]",
+                        "Invalid value for #[table(customize_fn = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("style_fn") {
+                style_fn = Some(match value {
+                    Lit::Str(lit_str) => lit_str.parse::<Expr>(),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(style_fn = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("skip_if") {
+                skip_if = Some(match value {
+                    Lit::Str(lit_str) => lit_str.parse::<Expr>(),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(skip_if = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("join") {
+                join = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(join = \"separator\")]",
+                    )),
+                }?);
+            } else if key.is_ident("lines") {
+                lines = Some(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool),
+                    bad => Err(Error::new_spanned(bad, "Invalid value for #[table(lines)]")),
+                }?);
+            } else if key.is_ident("max_items") {
+                max_items = Some(match value {
+                    Lit::Int(lit_int) => lit_int.base10_parse::<usize>().map(|n| (n, lit_int)),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(max_items = <usize>)]",
+                    )),
+                }?);
+            } else if key.is_ident("placeholder") {
+                placeholder = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(placeholder = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("format") {
+                format = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(format = \"value\")]",
                     )),
                 }?);
             } else if key.is_ident("skip") {
@@ -144,13 +241,125 @@ impl Field {
                     Lit::Bool(lit_bool) => Ok(lit_bool),
                     bad => Err(Error::new_spanned(bad, "Invalid value for #[table(bold)]")),
                 }?);
+            } else if key.is_ident("children") {
+                children = Some(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(children)]",
+                    )),
+                }?);
+            } else if key.is_ident("flatten") {
+                flatten = Some(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(flatten)]",
+                    )),
+                }?);
+            } else if key.is_ident("prefix") {
+                prefix = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(prefix = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("group") {
+                group = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(group = \"value\")]",
+                    )),
+                }?);
             }
         }
 
-        if let Some(skip) = skip {
-            if skip.value {
-                return Ok(None);
+        if let Some(skip) = skip
+            && skip.value
+        {
+            return Ok(None);
+        }
+
+        let description = get_doc(&field.attrs).filter(|_| container.doc_comments);
+
+        // First line of doc comment is used as the title when `title` is absent
+        let title = title
+            .or_else(|| {
+                description.as_ref().and_then(|description| {
+                    description
+                        .lines()
+                        .next()
+                        .map(|line| LitStr::new(line, span))
+                })
+            })
+            .or_else(|| {
+                container
+                    .rename_all
+                    .map(|rule| LitStr::new(&rule.apply(&ident.to_string()), span))
+            });
+        let justify = justify.or_else(|| container.justify.clone());
+        let align = align.or_else(|| container.align.clone());
+        let color = color.or_else(|| container.color.clone());
+
+        let lines = lines.filter(|lines| lines.value);
+
+        let collection = match (join, lines) {
+            (Some(_), Some(lines)) => {
+                return Err(Error::new_spanned(
+                    lines,
+                    "#[table(lines)] cannot be used along with #[table(join)]",
+                ));
+            }
+            (Some(join), None) => Some(Collection {
+                join: Some(join),
+                max_items: max_items.as_ref().map(|(n, _)| *n),
+            }),
+            (None, Some(_)) => Some(Collection {
+                join: None,
+                max_items: max_items.as_ref().map(|(n, _)| *n),
+            }),
+            (None, None) => {
+                if let Some((_, lit_int)) = max_items {
+                    return Err(Error::new_spanned(
+                        lit_int,
+                        "#[table(max_items)] can only be used along with #[table(join)] or #[table(lines)]",
+                    ));
+                }
+
+                None
             }
+        };
+
+        if collection.is_some()
+            && let Some(ref display_fn) = display_fn
+        {
+            return Err(Error::new_spanned(
+                display_fn,
+                "#[table(display_fn)] cannot be used along with #[table(join)] or #[table(lines)]",
+            ));
+        }
+
+        if collection.is_some()
+            && let Some(ref format) = format
+        {
+            return Err(Error::new_spanned(
+                format,
+                "#[table(format)] cannot be used along with #[table(join)] or #[table(lines)]",
+            ));
+        }
+
+        // Fields with `display_fn` receive the whole `Option` (including `None` values)
+        let optional = display_fn.is_none() && is_option(&field.ty);
+
+        if let Some(ref placeholder) = placeholder
+            && !optional
+        {
+            return Err(Error::new_spanned(
+                placeholder,
+                "#[table(placeholder)] can only be used on fields of type `Option<T>` without #[table(display_fn)]",
+            ));
         }
 
         let mut field_builder = Self::builder(ident, span);
@@ -180,13 +389,76 @@ impl Field {
         }
 
         if let Some(display_fn) = display_fn {
+            if let Some(format) = format {
+                return Err(Error::new_spanned(
+                    format,
+                    "#[table(format)] cannot be used along with #[table(display_fn)]",
+                ));
+            }
+
             field_builder.display_fn(display_fn);
         }
 
+        if let Some(format) = format {
+            field_builder.format(format);
+        }
+
         if let Some(customize_fn) = customize_fn {
             field_builder.customize_fn(customize_fn);
         }
 
+        if let Some(style_fn) = style_fn {
+            field_builder.style_fn(style_fn);
+        }
+
+        if let Some(skip_if) = skip_if {
+            if flatten.as_ref().is_some_and(|flatten| flatten.value) {
+                return Err(Error::new_spanned(
+                    skip_if,
+                    "#[table(skip_if)] cannot be used along with #[table(flatten)]",
+                ));
+            }
+
+            field_builder.skip_if(skip_if);
+        }
+
+        if let Some(collection) = collection {
+            field_builder.collection(collection);
+        }
+
+        field_builder.optional(optional);
+        field_builder.numeric(is_numeric(&field.ty));
+
+        if let Some(description) = description {
+            field_builder.description(description);
+        }
+
+        if let Some(placeholder) = placeholder {
+            field_builder.placeholder(placeholder);
+        }
+
+        if let Some(children) = children {
+            field_builder.children(children.value);
+        }
+
+        match flatten {
+            Some(flatten) if flatten.value => {
+                field_builder.flatten(Flatten {
+                    ty: field.ty.clone(),
+                    prefix,
+                    group,
+                });
+            }
+            _ => {
+                if let Some(attribute) = prefix.or(group) {
+                    return Err(Error::new_spanned(
+                        attribute,
+                        "#[table(prefix)] and #[table(group)] can only be used with #[table(flatten)]",
+                    ));
+                }
+            }
+        }
+
         Ok(Some(field_builder.build()))
     }
 
@@ -203,8 +475,18 @@ struct FieldBuilder {
     color: Option<Expr>,
     bold: Option<LitBool>,
     order: Option<usize>,
-    display_fn: Option<Ident>,
-    customize_fn: Option<Ident>,
+    display_fn: Option<Expr>,
+    format: Option<LitStr>,
+    customize_fn: Option<Expr>,
+    style_fn: Option<Expr>,
+    skip_if: Option<Expr>,
+    collection: Option<Collection>,
+    optional: bool,
+    placeholder: Option<LitStr>,
+    numeric: bool,
+    description: Option<String>,
+    children: bool,
+    flatten: Option<Flatten>,
     span: Span,
 }
 
@@ -219,7 +501,17 @@ impl FieldBuilder {
             bold: None,
             order: None,
             display_fn: None,
+            format: None,
             customize_fn: None,
+            style_fn: None,
+            skip_if: None,
+            collection: None,
+            optional: false,
+            placeholder: None,
+            numeric: false,
+            description: None,
+            children: false,
+            flatten: None,
             span,
         }
     }
@@ -254,16 +546,66 @@ impl FieldBuilder {
         self
     }
 
-    fn display_fn(&mut self, display_fn: Ident) -> &mut Self {
+    fn display_fn(&mut self, display_fn: Expr) -> &mut Self {
         self.display_fn = Some(display_fn);
         self
     }
 
-    fn customize_fn(&mut self, customize_fn: Ident) -> &mut Self {
+    fn format(&mut self, format: LitStr) -> &mut Self {
+        self.format = Some(format);
+        self
+    }
+
+    fn customize_fn(&mut self, customize_fn: Expr) -> &mut Self {
         self.customize_fn = Some(customize_fn);
         self
     }
 
+    fn style_fn(&mut self, style_fn: Expr) -> &mut Self {
+        self.style_fn = Some(style_fn);
+        self
+    }
+
+    fn skip_if(&mut self, skip_if: Expr) -> &mut Self {
+        self.skip_if = Some(skip_if);
+        self
+    }
+
+    fn collection(&mut self, collection: Collection) -> &mut Self {
+        self.collection = Some(collection);
+        self
+    }
+
+    fn optional(&mut self, optional: bool) -> &mut Self {
+        self.optional = optional;
+        self
+    }
+
+    fn placeholder(&mut self, placeholder: LitStr) -> &mut Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    fn numeric(&mut self, numeric: bool) -> &mut Self {
+        self.numeric = numeric;
+        self
+    }
+
+    fn description(&mut self, description: String) -> &mut Self {
+        self.description = Some(description);
+        self
+    }
+
+    fn children(&mut self, children: bool) -> &mut Self {
+        self.children = children;
+        self
+    }
+
+    fn flatten(&mut self, flatten: Flatten) -> &mut Self {
+        self.flatten = Some(flatten);
+        self
+    }
+
     fn build(self) -> Field {
         let ident = self.ident;
         let justify = self.justify;
@@ -272,7 +614,18 @@ impl FieldBuilder {
         let bold = self.bold;
         let order = self.order.unwrap_or(usize::MAX);
         let display_fn = self.display_fn;
+        let format = self.format;
         let customize_fn = self.customize_fn;
+        let style_fn = self.style_fn;
+        let skip_if = self.skip_if;
+        let collection = self.collection;
+        let optional = self.optional;
+        let placeholder = self.placeholder;
+        // Values displayed using `display_fn` or `format` may not be numbers even if the field is
+        let numeric = self.numeric && display_fn.is_none() && format.is_none();
+        let description = self.description;
+        let children = self.children;
+        let flatten = self.flatten;
         let span = self.span;
 
         let title = self
@@ -288,8 +641,69 @@ impl FieldBuilder {
             bold,
             order,
             display_fn,
+            format,
             customize_fn,
+            style_fn,
+            skip_if,
+            collection,
+            optional,
+            placeholder,
+            numeric,
+            description,
+            children,
+            flatten,
             span,
         }
     }
 }
+
+/// Returns `true` if given type is written as `Option<T>` (or `std::option::Option<T>`)
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+
+    type_path.qself.is_none()
+        && type_path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Option"
+                && matches!(
+                    segment.arguments,
+                    PathArguments::AngleBracketed(ref arguments) if arguments.args.len() == 1
+                )
+        })
+}
+
+/// Returns `true` if given type is a primitive numeric type (e.g., `u32` or `f64`) or a non-zero integer type,
+/// possibly wrapped in references and `Option`
+fn is_numeric(ty: &Type) -> bool {
+    const NUMERIC: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64",
+    ];
+
+    match ty {
+        Type::Reference(reference) => is_numeric(&reference.elem),
+        Type::Paren(paren) => is_numeric(&paren.elem),
+        Type::Group(group) => is_numeric(&group.elem),
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+
+            match segment.arguments {
+                PathArguments::None => {
+                    let ident = segment.ident.to_string();
+                    NUMERIC.contains(&ident.as_str()) || ident.starts_with("NonZero")
+                }
+                PathArguments::AngleBracketed(ref arguments) => {
+                    match (segment.ident.to_string().as_str(), arguments.args.first()) {
+                        ("Option" | "NonZero", Some(GenericArgument::Type(ty))) => is_numeric(ty),
+                        _ => false,
+                    }
+                }
+                PathArguments::Parenthesized(_) => false,
+            }
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,176 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    DataEnum, Error, Expr, Fields as SynFields, Ident, Lit, LitBool, LitStr, Result,
+    Variant as SynVariant, spanned::Spanned,
+};
+
+use crate::{
+    context::{Field, container::Container},
+    utils::get_attributes,
+};
+
+pub struct Variants {
+    variants: Vec<Variant>,
+}
+
+impl Variants {
+    pub fn new(data_enum: &DataEnum, container: &Container<'_>) -> Result<Self> {
+        let variants = data_enum
+            .variants
+            .iter()
+            .map(|variant| Variant::new(variant, container))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { variants })
+    }
+
+    /// Returns the titles of columns containing fields of variants (union of fields of all the variants, matched by
+    /// their titles)
+    pub fn titles(&self) -> Vec<LitStr> {
+        let mut columns: Vec<(&LitStr, usize)> = Vec::new();
+
+        for field in self
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+        {
+            let field = &field.1;
+
+            if !columns
+                .iter()
+                .any(|(title, _)| title.value() == field.title.value())
+            {
+                columns.push((&field.title, field.order));
+            }
+        }
+
+        columns.sort_by_key(|(_, order)| *order);
+
+        columns
+            .into_iter()
+            .map(|(title, _)| title.clone())
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Variant> {
+        self.variants.iter()
+    }
+}
+
+pub struct Variant {
+    pub ident: Ident,
+    pub title: LitStr,
+    pub color: Option<Expr>,
+    pub bold: Option<LitBool>,
+    /// Fields of the variant along with the names of variables they are bound to in the pattern of the variant
+    pub fields: Vec<(Ident, Field)>,
+    /// Pattern for matching the variant (e.g., `Enum::Variant { a: field_0, .. }`)
+    pub pattern: TokenStream,
+}
+
+impl Variant {
+    fn new(variant: &SynVariant, container: &Container<'_>) -> Result<Self> {
+        let name = container.name;
+        let span = variant.span();
+        let ident = variant.ident.clone();
+
+        let mut title = None;
+        let mut color = None;
+        let mut bold = None;
+
+        for (key, value) in get_attributes(&variant.attrs)? {
+            if key.is_ident("name") || key.is_ident("title") {
+                title = Some(match value {
+                    Lit::Str(lit_str) => Ok(lit_str),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(title = \"variant_name\")]",
+                    )),
+                }?);
+            } else if key.is_ident("color") {
+                color = Some(match value {
+                    Lit::Str(lit_str) => lit_str.parse::<Expr>(),
+                    bad => Err(Error::new_spanned(
+                        bad,
+                        "Invalid value for #[table(color = \"value\")]",
+                    )),
+                }?);
+            } else if key.is_ident("bold") {
+                bold = Some(match value {
+                    Lit::Bool(lit_bool) => Ok(lit_bool),
+                    bad => Err(Error::new_spanned(bad, "Invalid value for #[table(bold)]")),
+                }?);
+            }
+        }
+
+        let mut fields = Vec::new();
+        let mut bindings = Vec::new();
+
+        for (index, syn_field) in variant.fields.iter().enumerate() {
+            let binding = format_ident!("field_{}", index, span = Span::call_site());
+
+            match Field::new(syn_field, index, container)? {
+                Some(field) if field.children => {
+                    return Err(Error::new(
+                        field.span,
+                        "#[table(children)] cannot be used on fields of enum variants",
+                    ));
+                }
+                Some(field) if field.flatten.is_some() => {
+                    return Err(Error::new(
+                        field.span,
+                        "#[table(flatten)] cannot be used on fields of enum variants",
+                    ));
+                }
+                Some(Field {
+                    skip_if: Some(ref skip_if),
+                    ..
+                }) => {
+                    return Err(Error::new_spanned(
+                        skip_if,
+                        "#[table(skip_if)] cannot be used on fields of enum variants",
+                    ));
+                }
+                Some(field) => {
+                    bindings.push((syn_field.ident.as_ref(), binding.to_token_stream()));
+                    fields.push((binding, field));
+                }
+                None => bindings.push((syn_field.ident.as_ref(), quote!(_))),
+            }
+        }
+
+        let pattern = match variant.fields {
+            SynFields::Unit => quote!(#name :: #ident),
+            SynFields::Unnamed(_) => {
+                let bindings = bindings.iter().map(|(_, binding)| binding);
+                quote!(#name :: #ident ( #(#bindings),* ))
+            }
+            SynFields::Named(_) => {
+                let bindings = bindings
+                    .iter()
+                    .filter_map(|(field, binding)| field.map(|field| (field, binding)))
+                    .map(|(field, binding)| quote!(#field: #binding));
+                quote!(#name :: #ident { #(#bindings,)* .. })
+            }
+        };
+
+        let title = title.unwrap_or_else(|| {
+            let name = ident.to_string();
+
+            match container.rename_all {
+                Some(rule) => LitStr::new(&rule.apply(&name), span),
+                None => LitStr::new(&name, span),
+            }
+        });
+
+        Ok(Self {
+            ident,
+            title,
+            color,
+            bold,
+            fields,
+            pattern,
+        })
+    }
+}
@@ -1,8 +1,8 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{DeriveInput, Result};
+use syn::{DeriveInput, Expr, LitBool, Path, Result, spanned::Spanned};
 
-use crate::context::Context;
+use crate::context::{Container, Context, Data, Field, Fields, Variants};
 
 pub fn table(input: DeriveInput) -> Result<TokenStream> {
     // Create context for generating expressions
@@ -17,91 +17,111 @@ pub fn table(input: DeriveInput) -> Result<TokenStream> {
     // Split a type's generics into the pieces required for implementing a trait for that type
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let mut field_titles = Vec::new();
-    let mut field_rows = Vec::new();
-
-    for field in context.fields.into_iter() {
-        field_titles.push(field.title);
+    let index = context.container.index.map(|start| {
+        quote! {
+            let style = #cli_table ::Style::dimmed(#cli_table ::StyleStruct::default(), true);
+            let table = table.with_index(#start, style);
+        }
+    });
 
-        let ident = field.ident;
-        let justify = field.justify;
-        let align = field.align;
-        let color = field.color;
-        let bold = field.bold;
-        let display_fn = field.display_fn;
-        let customize_fn = field.customize_fn;
-        let span = field.span;
+    let border = context.container.border.as_ref().map(|border| {
+        quote! {
+            let table = table.border(#border);
+        }
+    });
 
-        let cell = match display_fn {
-            None => quote_spanned! {span=>
-                &self. #ident
-            },
-            Some(display_fn) => {
-                let span = display_fn.span();
-                quote_spanned! {span=>
-                    #display_fn (&self. #ident)
-                }
-            }
-        };
+    let separator = context.container.separator.as_ref().map(|separator| {
+        quote! {
+            let table = table.separator(#separator);
+        }
+    });
 
-        let mut row = quote_spanned! {span=>
-            #cli_table ::Cell::cell(#cell)
-        };
+    let (titles, row, columns, groups, skipped_columns, tree_impl) = match context.data {
+        Data::Struct(ref fields) => {
+            let (titles, row) = struct_row(fields, &context.container);
+            let columns = struct_columns(fields, &context.container);
+            let groups = struct_groups(fields, &context.container);
+            let skipped_columns = struct_skipped_columns(fields, &context.container);
 
-        if let Some(justify) = justify {
-            row = quote_spanned! {span=>
-                #row .justify(#justify)
-            };
-        }
+            let tree_impl = fields.children.as_ref().map(|children| {
+                quote! {
+                    #[automatically_derived]
+                    impl #impl_generics #cli_table ::Tree for #name #ty_generics #where_clause {
+                        fn children(&self) -> &[Self] {
+                            &self. #children
+                        }
+                    }
+                }
+            });
 
-        if let Some(align) = align {
-            row = quote_spanned! {span=>
-                #row .align(#align)
-            };
+            (titles, row, columns, groups, skipped_columns, tree_impl)
         }
-
-        if let Some(color) = color {
-            row = quote_spanned! {span=>
-                #cli_table ::Style::foreground_color(#row, ::core::convert::From::from(#color))
-            };
+        Data::Enum(ref variants) => {
+            let (titles, row) = enum_row(variants, &context.container);
+            let columns = enum_columns(variants, &context.container);
+            (titles, row, columns, None, None, None)
         }
+    };
 
-        if let Some(bold) = bold {
-            row = quote_spanned! {span=>
-                #cli_table ::Style::bold(#row, #bold)
-            };
-        }
+    let customize = [&index, &groups, &border, &separator];
 
-        if let Some(customize_fn) = customize_fn {
-            row = quote_spanned! {span=>
-                #customize_fn (#row, &self. #ident)
-            };
+    let customize_table = customize.iter().any(|tokens| tokens.is_some()).then(|| {
+        quote! {
+            fn customize_table(table: #cli_table ::TableStruct) -> #cli_table ::TableStruct {
+                #index
+                #groups
+                #border
+                #separator
+                table
+            }
         }
-
-        field_rows.push(row);
-    }
+    });
 
     // Build the output, possibly using quasi-quotation
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #cli_table ::Title for #name #ty_generics # where_clause{
             fn title() -> #cli_table ::RowStruct {
-                let title: ::std::vec::Vec<#cli_table ::CellStruct> = ::std::vec![
-                    #(#cli_table ::Style::bold(#cli_table ::Cell::cell(#field_titles), true),)*
-                ];
+                let mut title: ::std::vec::Vec<#cli_table ::CellStruct> = ::std::vec::Vec::new();
+                #(#titles)*
 
                 #cli_table ::Row::row(title)
             }
+
+            #customize_table
+
+            #skipped_columns
+        }
+
+        // Row is created in an inherent method so that `Self` in field attributes (e.g.,
+        // `display_fn = "Self::format_id"`) refers to the type itself instead of a reference to it
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics # where_clause{
+            #[doc(hidden)]
+            #[allow(clippy::needless_borrows_for_generic_args, clippy::redundant_closure_call)]
+            fn __cli_table_row(&self) -> #cli_table ::RowStruct {
+                #row
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #cli_table ::Schema for #name #ty_generics # where_clause{
+            fn columns() -> ::std::vec::Vec<#cli_table ::Column> {
+                let mut columns: ::std::vec::Vec<#cli_table ::Column> = ::std::vec::Vec::new();
+                #(#columns)*
+
+                for (order, column) in columns.iter_mut().enumerate() {
+                    column.order = order;
+                }
+
+                columns
+            }
         }
 
         #[automatically_derived]
         impl #impl_generics #cli_table ::Row for & #name #ty_generics # where_clause{
             fn row(self) -> #cli_table ::RowStruct {
-                let row: ::std::vec::Vec<#cli_table ::CellStruct> = ::std::vec![
-                    #(#field_rows,)*
-                ];
-
-                #cli_table ::Row::row(row)
+                self.__cli_table_row()
             }
         }
 
@@ -111,5 +131,505 @@ pub fn table(input: DeriveInput) -> Result<TokenStream> {
                 #cli_table ::Row::row(&self)
             }
         }
+
+        #tree_impl
     })
 }
+
+/// Returns the statements for adding titles to `title` and the expression for creating a row of a struct
+///
+/// Columns of flattened fields are spliced into the titles and the row.
+fn struct_row(fields: &Fields, container: &Container<'_>) -> (Vec<TokenStream>, TokenStream) {
+    let cli_table = &container.crate_name;
+    let mut field_titles = Vec::new();
+    let mut field_rows = Vec::new();
+
+    for field in fields.iter() {
+        let ident = &field.ident;
+        let span = field.span;
+
+        match field.flatten {
+            None => {
+                let title = &field.title;
+                let cell = title_style(quote! { #cli_table ::Cell::cell(#title) }, container);
+
+                field_titles.push(quote! { title.push(#cell); });
+
+                let cell = field_cell(
+                    field,
+                    quote_spanned! {span=> &self. #ident },
+                    None,
+                    cli_table,
+                );
+
+                field_rows.push(quote_spanned! {span=> row.push(#cell); });
+            }
+            Some(ref flatten) => {
+                let ty = &flatten.ty;
+
+                // Titles are kept as they are (including their style) unless they are prefixed
+                match flatten.prefix {
+                    None => field_titles.push(quote_spanned! {span=>
+                        title.extend_from_slice(<#ty as #cli_table ::Title>::title().cells());
+                    }),
+                    Some(ref prefix) => {
+                        let cell = title_style(
+                            quote! {
+                                #cli_table ::Cell::cell(::std::format!("{}{}", #prefix, cell.content()))
+                            },
+                            container,
+                        );
+
+                        field_titles.push(quote_spanned! {span=>
+                            title.extend(
+                                <#ty as #cli_table ::Title>::title()
+                                    .cells()
+                                    .iter()
+                                    .map(|cell| #cell),
+                            );
+                        });
+                    }
+                }
+
+                field_rows.push(quote_spanned! {span=>
+                    row.extend(#cli_table ::Row::row(&self. #ident).cells().iter().cloned());
+                });
+            }
+        }
+    }
+
+    let row = quote! {
+        let mut row: ::std::vec::Vec<#cli_table ::CellStruct> = ::std::vec::Vec::new();
+        #(#field_rows)*
+
+        #cli_table ::Row::row(row)
+    };
+
+    (field_titles, row)
+}
+
+/// Returns the statements for adding metadata of the columns of a struct to `columns`
+fn struct_columns(fields: &Fields, container: &Container<'_>) -> Vec<TokenStream> {
+    let cli_table = &container.crate_name;
+
+    fields
+        .iter()
+        .map(|field| {
+            let field_name = field.ident.to_string();
+            let span = field.span;
+
+            match field.flatten {
+                None => {
+                    let column = field_column(field, quote! { #field_name }, cli_table);
+                    quote! { columns.push(#column); }
+                }
+                Some(ref flatten) => {
+                    let ty = &flatten.ty;
+
+                    let name = match flatten.prefix {
+                        None => quote! { column.name },
+                        Some(ref prefix) => quote! { ::std::format!("{}{}", #prefix, column.name) },
+                    };
+
+                    quote_spanned! {span=>
+                        columns.extend(<#ty as #cli_table ::Schema>::columns().into_iter().map(|mut column| {
+                            column.name = #name;
+                            column.field = column
+                                .field
+                                .map(|field| ::std::format!("{}.{}", #field_name, field));
+                            column
+                        }));
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the statements for adding metadata of the columns of an enum to `columns`
+///
+/// Metadata of a column containing fields of multiple variants is taken from the first of those fields.
+fn enum_columns(variants: &Variants, container: &Container<'_>) -> Vec<TokenStream> {
+    let cli_table = &container.crate_name;
+
+    let kind_title = container.kind_column_title();
+
+    let kind = quote! {
+        columns.push(#cli_table ::Column::new(#kind_title));
+    };
+
+    let fields = variants.titles().into_iter().filter_map(|title| {
+        let field = variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .map(|(_, field)| field)
+            .find(|field| field.title.value() == title.value())?;
+
+        let field_name = field.ident.to_string();
+        let column = field_column(field, quote! { #field_name }, cli_table);
+
+        Some(quote! { columns.push(#column); })
+    });
+
+    std::iter::once(kind).chain(fields).collect()
+}
+
+/// Returns the expression for creating metadata of the column of a field
+fn field_column(field: &Field, field_name: TokenStream, cli_table: &Path) -> TokenStream {
+    let title = &field.title;
+    let numeric = field.numeric;
+
+    let description = match field.description {
+        Some(ref description) => quote! {
+            ::core::option::Option::Some(::std::string::String::from(#description))
+        },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    let justify = field.justify.as_ref().map(|justify| {
+        quote! { column.justify = #justify; }
+    });
+
+    quote! {
+        {
+            let mut column = #cli_table ::Column::new(#title);
+            column.field = ::core::option::Option::Some(::std::string::String::from(#field_name));
+            #justify
+            column.numeric = #numeric;
+            column.description = #description;
+            column
+        }
+    }
+}
+
+/// Returns the statements for adding a row containing group headers of flattened fields (if any) to `table`
+fn struct_groups(fields: &Fields, container: &Container<'_>) -> Option<TokenStream> {
+    let cli_table = &container.crate_name;
+
+    if fields.iter().all(|field| {
+        field
+            .flatten
+            .as_ref()
+            .is_none_or(|flatten| flatten.group.is_none())
+    }) {
+        return None;
+    }
+
+    let groups = fields.iter().map(|field| match field.flatten {
+        None => quote! { #cli_table ::Cell::cell("") },
+        Some(ref flatten) => {
+            let ty = &flatten.ty;
+            let span = field.span;
+
+            let cell = match flatten.group {
+                None => quote! { #cli_table ::Cell::cell("") },
+                Some(ref group) => {
+                    let cell = title_style(quote! { #cli_table ::Cell::cell(#group) }, container);
+                    quote! { #cell.justify(#cli_table ::format::Justify::Center) }
+                }
+            };
+
+            quote_spanned! {span=>
+                #cell.span(<#ty as #cli_table ::Title>::title().cells().len())
+            }
+        }
+    });
+
+    Some(quote! {
+        let groups: ::std::vec::Vec<#cli_table ::CellStruct> = ::std::vec![#(#groups,)*];
+        let table = table.column_groups(groups);
+    })
+}
+
+/// Returns the function for finding the columns to be skipped in a table (if any field has `skip_if` predicate)
+///
+/// A column is skipped when its predicate holds for all the rows of a non-empty table. Skipped columns of flattened
+/// fields are offset by the number of columns before them.
+fn struct_skipped_columns(fields: &Fields, container: &Container<'_>) -> Option<TokenStream> {
+    let cli_table = &container.crate_name;
+
+    if fields
+        .iter()
+        .all(|field| field.skip_if.is_none() && field.flatten.is_none())
+    {
+        return None;
+    }
+
+    // Columns can be skipped if any field has `skip_if` predicate or the type of a flattened field skips columns
+    let skips_columns = fields.iter().map(|field| match field.flatten {
+        Some(ref flatten) => {
+            let ty = &flatten.ty;
+            quote! { <#ty as #cli_table ::Title>::SKIPS_COLUMNS }
+        }
+        None => {
+            let skips = field.skip_if.is_some();
+            quote! { #skips }
+        }
+    });
+
+    let columns = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let span = field.span;
+
+        match (&field.flatten, &field.skip_if) {
+            (Some(flatten), _) => {
+                let ty = &flatten.ty;
+
+                quote_spanned! {span=>
+                    let children: ::std::vec::Vec<&#ty> = rows.iter().map(|row| &row. #ident).collect();
+                    skipped.extend(
+                        <#ty as #cli_table ::Title>::skipped_columns(&children)
+                            .into_iter()
+                            .map(|child| column + child),
+                    );
+                    column += <#ty as #cli_table ::Title>::title().cells().len();
+                }
+            }
+            (None, Some(skip_if)) => {
+                let span = skip_if.span();
+
+                quote_spanned! {span=>
+                    if !rows.is_empty() && rows.iter().all(|row| (#skip_if)(&row. #ident)) {
+                        skipped.push(column);
+                    }
+                    column += 1;
+                }
+            }
+            (None, None) => quote! { column += 1; },
+        }
+    });
+
+    Some(quote! {
+        const SKIPS_COLUMNS: bool = false #(|| #skips_columns)*;
+
+        #[allow(unused_assignments, clippy::redundant_closure_call)]
+        fn skipped_columns(rows: &[&Self]) -> ::std::vec::Vec<usize> {
+            let mut skipped = ::std::vec::Vec::new();
+            let mut column = 0;
+            #(#columns)*
+
+            skipped
+        }
+    })
+}
+
+/// Applies the style of title row (bold by default) to a cell
+fn title_style(mut cell: TokenStream, container: &Container<'_>) -> TokenStream {
+    let cli_table = &container.crate_name;
+
+    let bold = match container.title_bold {
+        Some(ref bold) => quote! { #bold },
+        None => quote! { true },
+    };
+
+    cell = quote! { #cli_table ::Style::bold(#cell, #bold) };
+
+    if let Some(ref color) = container.title_color {
+        cell = quote! {
+            #cli_table ::Style::foreground_color(#cell, ::core::convert::From::from(#color))
+        };
+    }
+
+    if let Some(ref background) = container.title_background {
+        cell = quote! {
+            #cli_table ::Style::background_color(#cell, ::core::convert::From::from(#background))
+        };
+    }
+
+    cell
+}
+
+/// Returns the statements for adding titles to `title` and the expression for creating a row of an enum
+///
+/// First column contains the name of the variant followed by the union of fields of all the variants (blank for
+/// fields which are not present in a variant).
+fn enum_row(variants: &Variants, container: &Container<'_>) -> (Vec<TokenStream>, TokenStream) {
+    let cli_table = &container.crate_name;
+    let titles = variants.titles();
+
+    let arms = variants.iter().map(|variant| {
+        let pattern = &variant.pattern;
+        let title = &variant.title;
+        let variant_style = (variant.color.as_ref(), variant.bold.as_ref());
+
+        let kind = style_cell(
+            quote! { #cli_table ::Cell::cell(#title) },
+            variant_style,
+            variant.ident.span(),
+            cli_table,
+        );
+
+        let cells = titles.iter().map(|title| {
+            match variant
+                .fields
+                .iter()
+                .find(|(_, field)| field.title.value() == title.value())
+            {
+                Some((binding, field)) => {
+                    field_cell(field, quote! { #binding }, Some(variant_style), cli_table)
+                }
+                None => style_cell(
+                    quote! { #cli_table ::Cell::cell("") },
+                    variant_style,
+                    variant.ident.span(),
+                    cli_table,
+                ),
+            }
+        });
+
+        quote! {
+            #pattern => ::std::vec![#kind, #(#cells,)*],
+        }
+    });
+
+    // Enums without variants cannot be instantiated, so there are no rows to create
+    let row = match variants.iter().next() {
+        None => quote! { match *self {} },
+        Some(_) => quote! {
+            let row: ::std::vec::Vec<#cli_table ::CellStruct> = match self {
+                #(#arms)*
+            };
+
+            #cli_table ::Row::row(row)
+        },
+    };
+
+    let titles = std::iter::once(container.kind_column_title())
+        .chain(titles)
+        .map(|title| {
+            let cell = title_style(quote! { #cli_table ::Cell::cell(#title) }, container);
+            quote! { title.push(#cell); }
+        })
+        .collect();
+
+    (titles, row)
+}
+
+/// Returns the expression for creating the cell of a field from given value (reference to the field)
+///
+/// Style of the variant (for enums) is applied before the styles of the field.
+fn field_cell(
+    field: &Field,
+    value: TokenStream,
+    variant_style: Option<(Option<&Expr>, Option<&LitBool>)>,
+    cli_table: &Path,
+) -> TokenStream {
+    let span = field.span;
+
+    // `Some` values of optional fields are displayed like other fields, `None` values as missing values
+    let inner = if field.optional {
+        quote! { value }
+    } else {
+        value.clone()
+    };
+
+    // Functions are parenthesized so that paths, generic calls and closures can all be called
+    let cell = match (&field.display_fn, &field.format, &field.collection) {
+        (Some(display_fn), _, _) => {
+            let span = display_fn.span();
+            quote_spanned! {span=>
+                (#display_fn)(#inner)
+            }
+        }
+        (None, Some(format), _) => {
+            let span = format.span();
+            quote_spanned! {span=>
+                ::std::format!(#format, #inner)
+            }
+        }
+        (None, None, Some(collection)) => {
+            let max_items = match collection.max_items {
+                Some(max_items) => quote! { ::core::option::Option::Some(#max_items) },
+                None => quote! { ::core::option::Option::None },
+            };
+
+            match collection.join {
+                Some(ref separator) => quote_spanned! {span=>
+                    #cli_table ::fmt::join(#inner, #separator, #max_items)
+                },
+                None => quote_spanned! {span=>
+                    #cli_table ::fmt::lines(#inner, #max_items)
+                },
+            }
+        }
+        (None, None, None) => inner,
+    };
+
+    let mut row = quote_spanned! {span=>
+        #cli_table ::Cell::cell(#cell)
+    };
+
+    if field.optional {
+        let none = match field.placeholder {
+            Some(ref placeholder) => quote! { #cli_table ::Cell::cell(#placeholder) },
+            None => quote! { #cli_table ::CellStruct::null() },
+        };
+
+        row = quote_spanned! {span=>
+            match #value {
+                ::core::option::Option::Some(value) => #row,
+                ::core::option::Option::None => #none,
+            }
+        };
+    }
+
+    if let Some(variant_style) = variant_style {
+        row = style_cell(row, variant_style, span, cli_table);
+    }
+
+    if let Some(ref justify) = field.justify {
+        row = quote_spanned! {span=>
+            #row .justify(#justify)
+        };
+    }
+
+    if let Some(ref align) = field.align {
+        row = quote_spanned! {span=>
+            #row .align(#align)
+        };
+    }
+
+    row = style_cell(
+        row,
+        (field.color.as_ref(), field.bold.as_ref()),
+        span,
+        cli_table,
+    );
+
+    if let Some(ref style_fn) = field.style_fn {
+        let span = style_fn.span();
+        row = quote_spanned! {span=>
+            #row .style((#style_fn)(#value))
+        };
+    }
+
+    if let Some(ref customize_fn) = field.customize_fn {
+        row = quote_spanned! {span=>
+            (#customize_fn)(#row, #value)
+        };
+    }
+
+    row
+}
+
+/// Applies color and boldness (if any) to a cell
+fn style_cell(
+    mut cell: TokenStream,
+    (color, bold): (Option<&Expr>, Option<&LitBool>),
+    span: Span,
+    cli_table: &Path,
+) -> TokenStream {
+    if let Some(color) = color {
+        cell = quote_spanned! {span=>
+            #cli_table ::Style::foreground_color(#cell, ::core::convert::From::from(#color))
+        };
+    }
+
+    if let Some(bold) = bold {
+        cell = quote_spanned! {span=>
+            #cli_table ::Style::bold(#cell, #bold)
+        };
+    }
+
+    cell
+}
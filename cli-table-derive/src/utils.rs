@@ -1,4 +1,4 @@
-use syn::{Attribute, Error, Lit, LitBool, Path, Result, spanned::Spanned};
+use syn::{Attribute, Error, Expr, ExprLit, Lit, LitBool, Meta, Path, Result, spanned::Spanned};
 
 pub fn get_attributes(attrs: &[Attribute]) -> Result<Vec<(Path, Lit)>> {
     let mut attributes = Vec::new();
@@ -36,3 +36,30 @@ pub fn get_attributes(attrs: &[Attribute]) -> Result<Vec<(Path, Lit)>> {
 
     Ok(attributes)
 }
+
+/// Returns the text of doc comments (`///` or `#[doc = "..."]`) in given attributes, if any
+pub fn get_doc(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attribute| match attribute.meta {
+            Meta::NameValue(ref meta) if meta.path.is_ident("doc") => match meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(ref lit_str),
+                    ..
+                }) => Some(lit_str.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let doc = lines.join("\n");
+    let doc = doc.trim();
+
+    (!doc.is_empty()).then(|| doc.to_owned())
+}
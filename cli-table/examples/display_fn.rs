@@ -0,0 +1,59 @@
+use std::{fmt::Display, io::Result};
+
+use cli_table::{CellStruct, Color, Style, Table, WithTitle, fmt, format::Justify, print_stdout};
+
+#[derive(Debug, Table)]
+struct Disk {
+    #[table(title = "ID", display_fn = "Self::format_id")]
+    id: u32,
+    #[table(title = "Name", display_fn = "|name: &str| name.to_uppercase()")]
+    name: &'static str,
+    #[table(title = "Size", display_fn = "fmt::bytes")]
+    size: u64,
+    #[table(title = "Used", format = "{:.1}%", justify = "Justify::Right")]
+    used: f64,
+    #[table(
+        title = "Temperature",
+        display_fn = "with_unit::<f32>",
+        customize_fn = "|cell: CellStruct, value: &f32| cell.bold(*value > 50.0)"
+    )]
+    temperature: f32,
+    #[table(
+        title = "Health",
+        customize_fn = "|cell: CellStruct, healthy: &bool| cell.foreground_color(Some(if *healthy { Color::Green } else { Color::Red }))"
+    )]
+    healthy: bool,
+}
+
+impl Disk {
+    fn format_id(id: &u32) -> String {
+        format!("disk{}", id)
+    }
+}
+
+fn with_unit<T: Display>(value: &T) -> String {
+    format!("{} °C", value)
+}
+
+fn main() -> Result<()> {
+    let disks = vec![
+        Disk {
+            id: 0,
+            name: "system",
+            size: 512 * 1024 * 1024 * 1024,
+            used: 73.25,
+            temperature: 41.5,
+            healthy: true,
+        },
+        Disk {
+            id: 1,
+            name: "backup",
+            size: 4 * 1024 * 1024 * 1024 * 1024,
+            used: 98.5,
+            temperature: 55.0,
+            healthy: false,
+        },
+    ];
+
+    print_stdout(disks.with_title())
+}
@@ -0,0 +1,34 @@
+use std::io::Result;
+
+use cli_table::{Color, Table, WithTitle, format::Justify, print_stdout};
+
+#[derive(Debug, Table)]
+enum Event {
+    #[table(title = "Login", color = "Color::Green")]
+    Login {
+        #[table(title = "User")]
+        user: &'static str,
+    },
+    #[table(title = "Transfer")]
+    Transfer {
+        #[table(title = "User")]
+        user: &'static str,
+        #[table(title = "Amount", justify = "Justify::Right")]
+        amount: u64,
+    },
+    #[table(title = "Logout", color = "Color::Red", bold)]
+    Logout,
+}
+
+fn main() -> Result<()> {
+    let events = vec![
+        Event::Login { user: "scooby" },
+        Event::Transfer {
+            user: "scooby",
+            amount: 250,
+        },
+        Event::Logout,
+    ];
+
+    print_stdout(events.with_title())
+}
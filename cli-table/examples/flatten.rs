@@ -0,0 +1,49 @@
+use std::io::Result;
+
+use cli_table::{Table, WithTitle, format::Justify, print_stdout};
+
+#[derive(Debug, Table)]
+struct Stats {
+    #[table(title = "cpu", justify = "Justify::Right")]
+    cpu: f32,
+    #[table(title = "mem", justify = "Justify::Right")]
+    mem: u64,
+}
+
+#[derive(Debug, Table)]
+#[table(index)]
+struct Process {
+    #[table(title = "ID", justify = "Justify::Right")]
+    id: u32,
+    #[table(title = "Name")]
+    name: &'static str,
+    #[table(flatten, group = "Current")]
+    current: Stats,
+    #[table(flatten, prefix = "peak.")]
+    peak: Stats,
+}
+
+fn main() -> Result<()> {
+    let processes = vec![
+        Process {
+            id: 1,
+            name: "init",
+            current: Stats { cpu: 0.1, mem: 12 },
+            peak: Stats { cpu: 3.2, mem: 20 },
+        },
+        Process {
+            id: 42,
+            name: "server",
+            current: Stats {
+                cpu: 12.5,
+                mem: 1024,
+            },
+            peak: Stats {
+                cpu: 80.0,
+                mem: 4096,
+            },
+        },
+    ];
+
+    print_stdout(processes.with_title())
+}
@@ -9,10 +9,7 @@ fn main() -> Result<()> {
         vec!["Tom".cell(), 10.cell().justify(Justify::Right)],
         vec!["Jerry".cell(), 15.cell().justify(Justify::Right)],
         vec!["Scooby Doo".cell(), 20.cell().justify(Justify::Right)],
-        vec![
-            "Nested".cell(),
-            nested_table.display()?.cell().justify(Justify::Right),
-        ],
+        vec!["Nested".cell(), nested_table.cell()],
     ]
     .table()
     .title(vec![
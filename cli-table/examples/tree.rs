@@ -0,0 +1,57 @@
+use std::io::Result;
+
+use cli_table::{
+    Table, Title, TreeTable,
+    format::{Justify, Separator},
+    print_stdout,
+};
+
+#[derive(Table)]
+struct Entry {
+    #[table(title = "Name")]
+    name: &'static str,
+    #[table(title = "Size (in KB)", justify = "Justify::Right")]
+    size: u64,
+    #[table(children)]
+    children: Vec<Entry>,
+}
+
+impl Entry {
+    fn new(name: &'static str, size: u64, children: Vec<Entry>) -> Self {
+        Self {
+            name,
+            size,
+            children,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let root = Entry::new(
+        "cli-table",
+        96,
+        vec![
+            Entry::new(
+                "src",
+                88,
+                vec![
+                    Entry::new("lib.rs", 8, vec![]),
+                    Entry::new("table.rs", 20, vec![]),
+                ],
+            ),
+            Entry::new("Cargo.toml", 1, vec![]),
+        ],
+    );
+
+    let table = TreeTable::from_trees(&[root])
+        .table()
+        .title(Entry::title())
+        .separator(
+            Separator::builder()
+                .column(Some(Default::default()))
+                .title(Some(Default::default()))
+                .build(),
+        );
+
+    print_stdout(table)
+}
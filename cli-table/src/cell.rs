@@ -1,6 +1,7 @@
 use std::{
     fmt::Display,
     io::{Result, Write},
+    sync::Arc,
 };
 
 use termcolor::{Buffer, BufferWriter, Color, ColorSpec, WriteColor};
@@ -8,17 +9,87 @@ use termcolor::{Buffer, BufferWriter, Color, ColorSpec, WriteColor};
 use crate::{
     row::Dimension as RowDimension,
     style::{Style, StyleStruct},
+    table::NestedTable,
     utils::display_width,
 };
 
+/// Function used for rendering the contents of a cell for given width
+pub(crate) type RenderFn = Arc<dyn Fn(usize) -> Vec<String> + Send + Sync>;
+
+/// Contents of a cell which are rendered once the width of its column is known
+#[derive(Clone)]
+pub(crate) struct Lazy {
+    pub(crate) render: RenderFn,
+    /// Minimum width for which the contents can be rendered
+    pub(crate) min_width: usize,
+}
+
 /// Concrete cell of a table
+#[derive(Clone)]
 pub struct CellStruct {
-    data: Vec<String>,
-    format: CellFormat,
-    style: StyleStruct,
+    pub(crate) data: Vec<String>,
+    pub(crate) format: CellFormat,
+    pub(crate) style: StyleStruct,
+    /// Table nested inside the cell
+    pub(crate) table: Option<Arc<NestedTable>>,
+    /// Contents of the cell rendered once the width of its column is known (`data` contains the contents rendered for
+    /// the width required by the cell)
+    pub(crate) lazy: Option<Lazy>,
+    /// Whether the cell represents a missing value
+    pub(crate) null: bool,
 }
 
 impl CellStruct {
+    /// Creates a cell whose contents are rendered by given function once the width of its column is known
+    ///
+    /// The cell requires `width` columns (its preferred width). It is rendered for a larger width when other cells in
+    /// its column are wider, and for a smaller width (down to its minimum width, see [`CellStruct::min_width`]) when
+    /// the table is wider than its maximum width (see [`TableStruct::max_width`](crate::TableStruct::max_width)).
+    ///
+    /// ```rust
+    /// use cli_table::{CellStruct, Table};
+    ///
+    /// let rule = CellStruct::lazy(3, |width| vec!["─".repeat(width)]);
+    /// let table = vec![vec![rule]].table();
+    /// ```
+    pub fn lazy<F>(width: usize, render: F) -> CellStruct
+    where
+        F: Fn(usize) -> Vec<String> + Send + Sync + 'static,
+    {
+        CellStruct {
+            data: render(width),
+            format: Default::default(),
+            style: Default::default(),
+            table: None,
+            lazy: Some(Lazy {
+                render: Arc::new(render),
+                min_width: width,
+            }),
+            null: false,
+        }
+    }
+
+    /// Creates a cell for a missing value (e.g., `None` or an empty CSV field)
+    ///
+    /// The cell is blank unless a placeholder is set for the table using [`TableStruct::null_placeholder`](crate::TableStruct::null_placeholder).
+    pub fn null() -> CellStruct {
+        CellStruct {
+            null: true,
+            ..Cell::cell("")
+        }
+    }
+
+    /// Used to set the minimum width for which the contents of a lazily rendered cell can be rendered (defaults to
+    /// the width required by the cell)
+    ///
+    /// This has no effect on cells which are not created using [`CellStruct::lazy`].
+    pub fn min_width(mut self, min_width: usize) -> CellStruct {
+        if let Some(ref mut lazy) = self.lazy {
+            lazy.min_width = min_width;
+        }
+        self
+    }
+
     /// Used to horizontally justify contents of a cell
     pub fn justify(mut self, justify: Justify) -> CellStruct {
         self.format.justify = justify;
@@ -37,30 +108,125 @@ impl CellStruct {
         self
     }
 
+    /// Used to merge the outer border of a nested table into the edges of the cell
+    ///
+    /// When set, the nested table is printed without its border and padding so that its columns touch the separators
+    /// of parent table. This has no effect on cells which do not contain a nested table.
+    pub fn merge_border(mut self, merge_border: bool) -> CellStruct {
+        self.format.merge_border = merge_border;
+        self
+    }
+
+    /// Used to apply given style on top of the current style of a cell
+    ///
+    /// Colors set in given style replace the colors of the cell while other attributes (e.g., boldness) are added to
+    /// the existing ones.
+    pub fn style(mut self, style: StyleStruct) -> CellStruct {
+        self.style = self.style.overlay(style);
+        self
+    }
+
+    /// Used to make a cell span given number of columns (defaults to `1`)
+    ///
+    /// Spanning cells are meant for header rows (e.g., [`TableStruct::column_groups`](crate::TableStruct::column_groups)). Borders and separators around
+    /// spanning cells in other rows are drawn as if each cell occupies a single column.
+    pub fn span(mut self, columns: usize) -> CellStruct {
+        self.format.span = columns;
+        self
+    }
+
+    /// Returns the contents of a cell
+    pub fn content(&self) -> String {
+        self.data.join("\n")
+    }
+
     fn color_spec(&self) -> ColorSpec {
         self.style.color_spec()
     }
 
+    /// Returns the number of columns spanned by the cell
+    pub(crate) fn columns(&self) -> usize {
+        std::cmp::max(self.format.span, 1)
+    }
+
+    /// Returns `true` if the cell contains a nested table whose border is merged into the edges of the cell
+    pub(crate) fn is_merged(&self) -> bool {
+        self.format.merge_border && self.table.is_some()
+    }
+
+    /// Returns the padding of the cell (cells containing a nested table with merged border have no padding)
+    fn effective_padding(&self) -> Padding {
+        if self.is_merged() {
+            Default::default()
+        } else {
+            self.format.padding
+        }
+    }
+
     /// Returns the minimum dimensions required by the cell
     pub(crate) fn required_dimension(&self) -> Dimension {
-        let height = self.data.len() + self.format.padding.top + self.format.padding.bottom;
+        let padding = self.effective_padding();
+
+        if let Some(ref table) = self.table {
+            let dimension = table.dimension(self.format.merge_border);
+
+            // Merged tables also occupy the spaces around the cell
+            let width = match self.is_merged() {
+                true => dimension.width.saturating_sub(2),
+                false => dimension.width + padding.left + padding.right,
+            };
+
+            return Dimension {
+                width,
+                height: dimension.height + padding.top + padding.bottom,
+            };
+        }
+
+        let height = self.data.len() + padding.top + padding.bottom;
         let width = self
             .data
             .iter()
             .map(|x| display_width(x))
             .max()
             .unwrap_or_default()
-            + self.format.padding.left
-            + self.format.padding.right;
+            + padding.left
+            + padding.right;
 
         Dimension { width, height }
     }
 
+    /// Returns the minimum width required by the cell (smaller than required width for lazily rendered cells)
+    pub(crate) fn min_width_required(&self) -> usize {
+        let width = self.required_dimension().width;
+
+        match self.lazy {
+            Some(ref lazy) => {
+                let padding = self.effective_padding();
+                std::cmp::min(lazy.min_width + padding.left + padding.right, width)
+            }
+            None => width,
+        }
+    }
+
     pub(crate) fn buffers(
         &self,
         writer: &BufferWriter,
         available_dimension: Dimension,
     ) -> Result<Vec<Buffer>> {
+        if let Some(ref lazy) = self.lazy {
+            let padding = self.effective_padding();
+            let mut data = (lazy.render)(available_dimension.width - padding.left - padding.right);
+            data.truncate(available_dimension.height - padding.top - padding.bottom);
+
+            let cell = CellStruct {
+                data,
+                lazy: None,
+                ..self.clone()
+            };
+
+            return cell.buffers(writer, available_dimension);
+        }
+
         let required_dimension = self.required_dimension();
         let mut buffers = Vec::with_capacity(available_dimension.height);
 
@@ -71,6 +237,10 @@ impl CellStruct {
 
         let top_blank_lines = self.top_blank_lines(available_dimension, required_dimension);
 
+        if let Some(ref table) = self.table {
+            return self.nested_buffers(table, writer, available_dimension, top_blank_lines);
+        }
+
         for _ in 0..top_blank_lines {
             buffers.push(self.buffer(writer, available_dimension, required_dimension, "")?);
         }
@@ -86,6 +256,46 @@ impl CellStruct {
         Ok(buffers)
     }
 
+    /// Returns the buffers for each line of a cell containing a nested table
+    fn nested_buffers(
+        &self,
+        table: &NestedTable,
+        writer: &BufferWriter,
+        available_dimension: Dimension,
+        top_blank_lines: usize,
+    ) -> Result<Vec<Buffer>> {
+        let padding = self.effective_padding();
+
+        let width = match self.is_merged() {
+            true => available_dimension.width + 2,
+            false => available_dimension.width - padding.left - padding.right,
+        };
+
+        let lines = table.buffers(writer, width, self.format.merge_border)?;
+        let mut buffers = Vec::with_capacity(available_dimension.height);
+
+        for i in 0..available_dimension.height {
+            let mut buffer = writer.buffer();
+
+            match i.checked_sub(top_blank_lines).and_then(|i| lines.get(i)) {
+                Some(line) => {
+                    write!(buffer, "{}", " ".repeat(padding.left))?;
+                    buffer.write_all(line.as_slice())?;
+                    write!(buffer, "{}", " ".repeat(padding.right))?;
+                }
+                None => write!(
+                    buffer,
+                    "{}",
+                    " ".repeat(padding.left + width + padding.right)
+                )?,
+            }
+
+            buffers.push(buffer);
+        }
+
+        Ok(buffers)
+    }
+
     fn buffer(
         &self,
         writer: &BufferWriter,
@@ -93,14 +303,15 @@ impl CellStruct {
         required_dimension: Dimension,
         data: &str,
     ) -> Result<Buffer> {
+        let padding = self.effective_padding();
+
         let empty_chars = match self.format.justify {
-            Justify::Left => self.format.padding.left,
-            Justify::Right => {
-                (available_dimension.width - required_dimension.width) + self.format.padding.left
+            Justify::Left => padding.left,
+            Justify::Right | Justify::On(_) => {
+                (available_dimension.width - required_dimension.width) + padding.left
             }
             Justify::Center => {
-                ((available_dimension.width - required_dimension.width) / 2)
-                    + self.format.padding.left
+                ((available_dimension.width - required_dimension.width) / 2) + padding.left
             }
         };
 
@@ -125,14 +336,13 @@ impl CellStruct {
         available_dimension: Dimension,
         required_dimension: Dimension,
     ) -> usize {
+        let padding = self.effective_padding();
+
         match self.format.align {
-            Align::Top => self.format.padding.top,
-            Align::Bottom => {
-                (available_dimension.height - required_dimension.height) + self.format.padding.top
-            }
+            Align::Top => padding.top,
+            Align::Bottom => (available_dimension.height - required_dimension.height) + padding.top,
             Align::Center => {
-                ((available_dimension.height - required_dimension.height) / 2)
-                    + self.format.padding.top
+                ((available_dimension.height - required_dimension.height) / 2) + padding.top
             }
         }
     }
@@ -155,6 +365,9 @@ where
             data,
             format: Default::default(),
             style: Default::default(),
+            table: None,
+            lazy: None,
+            null: false,
         }
     }
 }
@@ -204,33 +417,39 @@ impl Style for CellStruct {
 
 /// Struct for configuring a cell's format
 #[derive(Debug, Clone, Copy, Default)]
-struct CellFormat {
-    justify: Justify,
-    align: Align,
-    padding: Padding,
+pub(crate) struct CellFormat {
+    pub(crate) justify: Justify,
+    pub(crate) align: Align,
+    pub(crate) padding: Padding,
+    pub(crate) merge_border: bool,
+    pub(crate) span: usize,
 }
 
 /// Used to horizontally justify contents of a cell
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Justify {
     /// Justifies contents to left
+    #[default]
     Left,
     /// Justifies contents to right
     Right,
     /// Justifies contents to center
     Center,
-}
-
-impl Default for Justify {
-    fn default() -> Self {
-        Self::Left
-    }
+    /// Justifies contents so that the first occurrence of given character (e.g., decimal point) lines up in all the
+    /// rows of a column
+    ///
+    /// Lines which do not contain the character are aligned as if it was present at their end. Contents are otherwise
+    /// justified to right. Lazily rendered cells (see [`CellStruct::lazy`]) and cells containing nested tables are only
+    /// justified to right.
+    On(char),
 }
 
 /// Used to vertically align contents of a cell
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum Align {
     /// Aligns contents to top
+    #[default]
     Top,
     /// Aligns contents to bottom
     Bottom,
@@ -238,12 +457,6 @@ pub enum Align {
     Center,
 }
 
-impl Default for Align {
-    fn default() -> Self {
-        Self::Top
-    }
-}
-
 /// Used to add padding to the contents of a cell
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Padding {
@@ -0,0 +1,144 @@
+use crate::cell::{Cell, CellStruct};
+
+/// Characters used for drawing partially filled blocks of a bar (in eighths of a block)
+const BAR_BLOCKS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Characters used for drawing a sparkline (from lowest to highest)
+const SPARK_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// A horizontal bar whose length is proportional to a value (e.g., `████▌     `)
+///
+/// Bar is stretched to fill the width of its column. Colors of the bar can be changed using functions of
+/// [`Style`](crate::Style) trait on the cell.
+///
+/// ```rust
+/// use cli_table::{Bar, Cell, Color, Style, Table};
+///
+/// let table = vec![
+///     vec!["sda".cell(), Bar::new(45.0, 100.0).cell().foreground_color(Some(Color::Green))],
+///     vec!["sdb".cell(), Bar::new(90.0, 100.0).cell().foreground_color(Some(Color::Red))],
+/// ]
+/// .table();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    value: f64,
+    max: f64,
+    width: usize,
+}
+
+impl Bar {
+    /// Creates a new bar for `value` out of `max`
+    pub fn new(value: f64, max: f64) -> Self {
+        Self {
+            value,
+            max,
+            width: 10,
+        }
+    }
+
+    /// Used to set the minimum width of the bar (defaults to `10`)
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn render(&self, width: usize) -> String {
+        let ratio = if self.max > 0.0 {
+            (self.value / self.max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let eighths = (ratio * (width * 8) as f64).round() as usize;
+        let mut bar = "█".repeat(eighths / 8);
+
+        if !eighths.is_multiple_of(8) {
+            bar.push(BAR_BLOCKS[eighths % 8]);
+        }
+
+        format!("{:width$}", bar, width = width)
+    }
+}
+
+impl Cell for Bar {
+    fn cell(self) -> CellStruct {
+        CellStruct::lazy(self.width, move |width| vec![self.render(width)])
+    }
+}
+
+/// A sparkline showing the trend of a series of values (e.g., `▁▂▅█▃`)
+///
+/// When the column is narrower than the number of values, only the latest values are displayed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sparkline {
+    values: Vec<f64>,
+}
+
+impl Sparkline {
+    /// Creates a new sparkline for given values
+    pub fn new<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+        }
+    }
+
+    fn render(&self, width: usize) -> String {
+        let values = &self.values[self.values.len().saturating_sub(width)..];
+
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let sparkline: String = values
+            .iter()
+            .map(|value| {
+                let ratio = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+
+                SPARK_BLOCKS[(ratio * 7.0).round() as usize]
+            })
+            .collect();
+
+        format!("{:width$}", sparkline, width = width)
+    }
+}
+
+impl Cell for Sparkline {
+    fn cell(self) -> CellStruct {
+        let width = self.values.len();
+
+        CellStruct::lazy(width, move |width| vec![self.render(width)]).min_width(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{ColorChoice, Table};
+
+    #[test]
+    fn test_bar_fills_column() {
+        let table = vec![
+            vec![Bar::new(45.0, 100.0).width(4).cell()],
+            vec!["1234567890".cell()],
+        ]
+        .table()
+        .color_choice(ColorChoice::Never);
+
+        let display = table.display().unwrap().to_string();
+
+        assert_eq!("| ████▌      |", display.lines().nth(1).unwrap());
+    }
+
+    #[test]
+    fn test_sparkline() {
+        let sparkline = Sparkline::new([1.0, 2.0, 5.0, 8.0, 3.0]);
+
+        assert_eq!("▁▂▅█▃", sparkline.render(5));
+        assert_eq!("▄█▁", sparkline.render(3));
+    }
+}
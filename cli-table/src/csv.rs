@@ -2,7 +2,7 @@ use std::{convert::TryFrom, io::Read};
 
 use csv::{Error, Reader, StringRecord};
 
-use crate::{Cell, RowStruct, Style, Table, TableStruct};
+use crate::{Cell, CellStruct, RowStruct, Style, Table, TableStruct};
 
 impl<R: Read> TryFrom<&mut Reader<R>> for TableStruct {
     type Error = Error;
@@ -26,10 +26,17 @@ impl<R: Read> TryFrom<&mut Reader<R>> for TableStruct {
     }
 }
 
+/// Converts a record into a row (empty fields are converted into cells for missing values)
 fn row(record: &StringRecord) -> RowStruct {
-    RowStruct {
-        cells: record.iter().map(Cell::cell).collect(),
-    }
+    let cells = record
+        .iter()
+        .map(|field| match field {
+            "" => CellStruct::null(),
+            field => field.cell(),
+        })
+        .collect();
+
+    RowStruct { cells }
 }
 
 fn title(record: &StringRecord) -> RowStruct {
@@ -37,3 +44,31 @@ fn title(record: &StringRecord) -> RowStruct {
         cells: record.iter().map(|cell| cell.cell().bold(true)).collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ColorChoice;
+
+    #[test]
+    fn test_empty_fields() {
+        let mut reader = Reader::from_reader("Name,Port\nweb,80\ndb,\n".as_bytes());
+
+        let table = TableStruct::try_from(&mut reader)
+            .unwrap()
+            .null_placeholder("n/a")
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+------+------+\n\
+             | Name | Port |\n\
+             +------+------+\n\
+             | web  | 80   |\n\
+             +------+------+\n\
+             | db   | n/a  |\n\
+             +------+------+",
+            table.display().unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Formatters for common kinds of values (numbers, byte sizes, durations, etc.)
+//!
+//! Formatters for numbers, byte sizes, durations and timestamps return right-justified cells. All the formatters can be
+//! used directly with `display_fn` attribute of `#[derive(Table)]` (formatters for collections are used by `join` and
+//! `lines` attributes).
+//!
+//! ```rust
+//! use cli_table::{
+//!     fmt::{bytes, percent},
+//!     Table,
+//! };
+//!
+//! #[derive(Table)]
+//! struct Disk {
+//!     #[table(title = "Name")]
+//!     name: &'static str,
+//!     #[table(title = "Size", display_fn = "bytes")]
+//!     size: u64,
+//!     #[table(title = "Used", display_fn = "percent")]
+//!     used: f64,
+//! }
+//! ```
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime},
+};
+
+use crate::cell::{Cell, CellStruct, Justify};
+
+/// Units used for formatting byte sizes in powers of 1024
+const IEC_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Units used for formatting byte sizes in powers of 1000
+const SI_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Configurable formatter for numbers
+///
+/// ```rust
+/// use cli_table::fmt::NumberFormat;
+///
+/// let format = NumberFormat::new().precision(2).thousands(',');
+///
+/// assert_eq!("12,345.68", format.format(&12345.678));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberFormat {
+    precision: Option<usize>,
+    thousands: Option<char>,
+}
+
+impl NumberFormat {
+    /// Creates a new number formatter which prints numbers as they are
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Used to print numbers with given number of digits after decimal point
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Used to separate groups of three digits in integer part of numbers with given character
+    pub fn thousands(mut self, separator: char) -> Self {
+        self.thousands = Some(separator);
+        self
+    }
+
+    /// Formats given number (values which are not numbers are returned as they are)
+    pub fn format<T: Display + ?Sized>(&self, value: &T) -> String {
+        let mut number = value.to_string();
+
+        if let Some(precision) = self.precision
+            && let Ok(value) = number.parse::<f64>()
+        {
+            number = format!("{:.*}", precision, value);
+        }
+
+        match self.thousands {
+            Some(separator) => group_thousands(&number, separator),
+            None => number,
+        }
+    }
+
+    /// Formats given number into a right-justified cell
+    pub fn cell<T: Display + ?Sized>(&self, value: &T) -> CellStruct {
+        numeric(self.format(value))
+    }
+}
+
+/// Formats a number with groups of three digits separated by commas (e.g., `12,345,678`)
+pub fn thousands<T: Display + ?Sized>(value: &T) -> CellStruct {
+    NumberFormat::new().thousands(',').cell(value)
+}
+
+/// Formats a number with two digits after decimal point (e.g., `1.50`)
+pub fn fixed<T: Display + ?Sized>(value: &T) -> CellStruct {
+    NumberFormat::new().precision(2).cell(value)
+}
+
+/// Formats a ratio as a percentage with one digit after decimal point (e.g., `0.42` as `42.0%`)
+pub fn percent<T: Into<f64> + Copy>(value: &T) -> CellStruct {
+    numeric(format!("{:.1}%", (*value).into() * 100.0))
+}
+
+/// Formats a number of bytes using binary (IEC) units (e.g., `1.2 GiB`)
+///
+/// Number of bytes can be any unsigned integer type (including `usize`).
+pub fn bytes<T: ByteCount>(value: &T) -> CellStruct {
+    numeric(format_bytes(value.to_u64(), 1024, &IEC_UNITS))
+}
+
+/// Formats a number of bytes using decimal (SI) units (e.g., `1.3 GB`)
+///
+/// Number of bytes can be any unsigned integer type (including `usize`).
+pub fn si_bytes<T: ByteCount>(value: &T) -> CellStruct {
+    numeric(format_bytes(value.to_u64(), 1000, &SI_UNITS))
+}
+
+/// Formats a duration using its two largest units (e.g., `3h 4m`)
+pub fn duration(value: &Duration) -> CellStruct {
+    numeric(format_duration(*value))
+}
+
+/// Formats a timestamp relative to current time (e.g., `5m 12s ago` or `in 2d 3h`)
+pub fn relative_time(value: &SystemTime) -> CellStruct {
+    numeric(format_relative_time(*value, SystemTime::now()))
+}
+
+/// Joins the items of a collection with given separator (e.g., `a, b, c`)
+///
+/// When `max_items` is given, only that many items are displayed followed by the number of remaining items (e.g.,
+/// `a, b, +3 more`).
+pub fn join<I>(items: I, separator: &str, max_items: Option<usize>) -> CellStruct
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut items = items.into_iter();
+
+    let mut parts: Vec<String> = items
+        .by_ref()
+        .take(max_items.unwrap_or(usize::MAX))
+        .map(|item| item.to_string())
+        .collect();
+
+    let remaining = items.count();
+
+    if remaining > 0 {
+        parts.push(format!("+{} more", remaining));
+    }
+
+    parts.join(separator).cell()
+}
+
+/// Displays the items of a collection on separate lines of a cell
+///
+/// When `max_items` is given, only that many items are displayed followed by the number of remaining items on the
+/// last line (e.g., `+3 more`).
+pub fn lines<I>(items: I, max_items: Option<usize>) -> CellStruct
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    join(items, "\n", max_items)
+}
+
+/// Unsigned integer types which can be formatted as byte sizes (`u8`, `u16`, `u32`, `u64` and `usize`)
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait ByteCount: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn to_u64(self) -> u64;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_byte_count {
+    ($($ty:ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl ByteCount for $ty {
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_count!(u8, u16, u32, u64, usize);
+
+/// Creates a right-justified cell
+fn numeric(text: String) -> CellStruct {
+    text.cell().justify(Justify::Right)
+}
+
+/// Separates groups of three digits in the integer part of a number with given character
+pub(crate) fn group_thousands(number: &str, separator: char) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", number),
+    };
+
+    let end = unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let (digits, rest) = unsigned.split_at(end);
+
+    let mut grouped = String::with_capacity(number.len() + digits.len() / 3);
+    grouped.push_str(sign);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped.push_str(rest);
+    grouped
+}
+
+/// Formats a number of bytes using the largest unit for which the value is at least `1`
+fn format_bytes(bytes: u64, base: u64, units: &[&str]) -> String {
+    if bytes < base {
+        return format!("{} {}", bytes, units[0]);
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= base as f64 && unit + 1 < units.len() {
+        value /= base as f64;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, units[unit])
+}
+
+/// Formats a timestamp relative to given current time (timestamps less than a second away are displayed as `now`)
+fn format_relative_time(time: SystemTime, now: SystemTime) -> String {
+    match now.duration_since(time) {
+        Ok(elapsed) if elapsed.as_secs() == 0 => "now".to_string(),
+        Ok(elapsed) => format!("{} ago", format_duration(elapsed)),
+        Err(error) if error.duration().as_secs() == 0 => "now".to_string(),
+        Err(error) => format!("in {}", format_duration(error.duration())),
+    }
+}
+
+/// Formats a duration using its two largest non-zero units
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    if seconds == 0 {
+        return format!("{}ms", duration.subsec_millis());
+    }
+
+    let units = [
+        (seconds / 86400, "d"),
+        (seconds % 86400 / 3600, "h"),
+        (seconds % 3600 / 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let first = units
+        .iter()
+        .position(|(value, _)| *value > 0)
+        .unwrap_or_default();
+
+    units[first..]
+        .iter()
+        .take(2)
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!("7", group_thousands("7", ' '));
+        assert_eq!("999", group_thousands("999", ' '));
+        assert_eq!("1 234", group_thousands("1234", ' '));
+        assert_eq!("-1,234,567.891", group_thousands("-1234567.891", ','));
+    }
+
+    #[test]
+    fn test_number_format() {
+        assert_eq!("12,345,678", thousands(&12345678).content());
+        assert_eq!("-1,234.5", thousands(&-1234.5).content());
+        assert_eq!("1.50", fixed(&1.499).content());
+        assert_eq!("42.0%", percent(&0.42).content());
+        assert_eq!("n/a", NumberFormat::new().precision(2).format("n/a"));
+    }
+
+    #[test]
+    fn test_join() {
+        let tags = ["web", "db", "cache", "queue"];
+
+        assert_eq!("web, db, cache, queue", join(&tags, ", ", None).content());
+        assert_eq!("web, db, +2 more", join(&tags, ", ", Some(2)).content());
+        assert_eq!("web\n+3 more", lines(&tags, Some(1)).content());
+    }
+
+    #[test]
+    fn test_bytes_and_duration() {
+        assert_eq!("512 B", bytes(&512u64).content());
+        assert_eq!("1.2 GiB", bytes(&(1_288_490_189u64)).content());
+        assert_eq!("1.3 GB", si_bytes(&(1_288_490_189u64)).content());
+        assert_eq!("3h 4m", duration(&Duration::from_secs(11040)).content());
+        assert_eq!("2d", duration(&Duration::from_secs(172830)).content());
+        assert_eq!("350ms", duration(&Duration::from_millis(350)).content());
+        assert_eq!("2.0 KiB", bytes(&vec![0u8; 2048].len()).content());
+        assert_eq!("255 B", si_bytes(&255u8).content());
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let relative = |seconds: i64| {
+            let time = match seconds {
+                0.. => now + Duration::from_secs(seconds as u64),
+                _ => now - Duration::from_secs(seconds.unsigned_abs()),
+            };
+
+            format_relative_time(time, now)
+        };
+
+        assert_eq!("now", relative(0));
+        assert_eq!(
+            "now",
+            format_relative_time(now - Duration::from_millis(999), now)
+        );
+        assert_eq!(
+            "now",
+            format_relative_time(now + Duration::from_millis(999), now)
+        );
+        assert_eq!("1s ago", relative(-1));
+        assert_eq!("59s ago", relative(-59));
+        assert_eq!("1m ago", relative(-60));
+        assert_eq!("59m 59s ago", relative(-3599));
+        assert_eq!("1h ago", relative(-3600));
+        assert_eq!("23h 59m ago", relative(-86399));
+        assert_eq!("1d ago", relative(-86400));
+        assert_eq!("in 1s", relative(1));
+        assert_eq!("in 1m 1s", relative(61));
+        assert_eq!("in 1h", relative(3600));
+        assert_eq!("in 2d 3h", relative(183600));
+    }
+}
@@ -0,0 +1,269 @@
+use std::io::{Result, Write};
+
+use termcolor::{BufferWriter, ColorChoice};
+
+use crate::{cell::Align, display::TableDisplay, table::TableStruct, utils::display_width};
+
+/// Direction in which the tables of a layout are arranged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Tables are printed next to each other
+    Horizontal,
+    /// Tables are printed one below another
+    Vertical,
+}
+
+/// Arrangement of multiple tables printed together
+///
+/// Tables can either be placed side-by-side (e.g., to compare `before` and `after` tables) or stacked on top of each
+/// other with shared column widths.
+///
+/// ```rust
+/// use cli_table::{ColorChoice, Table, TableLayout};
+///
+/// let before = vec![vec![1, 2]].table().title(vec!["A", "B"]);
+/// let after = vec![vec![3, 4]].table().title(vec!["A", "B"]);
+///
+/// let layout = TableLayout::horizontal([before, after]).color_choice(ColorChoice::Never);
+///
+/// assert!(layout.print_stdout().is_ok());
+/// ```
+pub struct TableLayout {
+    tables: Vec<TableStruct>,
+    direction: Direction,
+    gap: usize,
+    align: Align,
+    color_choice: ColorChoice,
+}
+
+impl TableLayout {
+    /// Creates a layout which prints given tables next to each other, separated by two spaces
+    pub fn horizontal<I: IntoIterator<Item = TableStruct>>(tables: I) -> Self {
+        Self::new(tables, Direction::Horizontal, 2)
+    }
+
+    /// Creates a layout which prints given tables one below another, separated by an empty line
+    ///
+    /// Columns at the same position in all the tables have the same width.
+    pub fn vertical<I: IntoIterator<Item = TableStruct>>(tables: I) -> Self {
+        Self::new(tables, Direction::Vertical, 1)
+    }
+
+    fn new<I: IntoIterator<Item = TableStruct>>(
+        tables: I,
+        direction: Direction,
+        gap: usize,
+    ) -> Self {
+        Self {
+            tables: tables.into_iter().collect(),
+            direction,
+            gap,
+            align: Default::default(),
+            color_choice: ColorChoice::Always,
+        }
+    }
+
+    /// Used to set the gap between tables (in spaces for horizontal layout and in lines for vertical layout)
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Used to vertically align tables of different heights in a horizontal layout
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Used to set the color preferences for printing the layout
+    pub fn color_choice(mut self, color_choice: ColorChoice) -> Self {
+        self.color_choice = color_choice;
+        self
+    }
+
+    /// Returns a struct which implements the `Display` trait
+    pub fn display(&self) -> Result<TableDisplay> {
+        let writer = BufferWriter::stdout(self.color_choice);
+
+        Ok(TableDisplay::new(self.output(&writer)?))
+    }
+
+    /// Prints current layout to `stdout`
+    pub fn print_stdout(&self) -> Result<()> {
+        self.print_writer(BufferWriter::stdout(self.color_choice))
+    }
+
+    /// Prints current layout to `stderr`
+    pub fn print_stderr(&self) -> Result<()> {
+        self.print_writer(BufferWriter::stderr(self.color_choice))
+    }
+
+    fn print_writer(&self, writer: BufferWriter) -> Result<()> {
+        let mut buffer = writer.buffer();
+        buffer.write_all(&self.output(&writer)?)?;
+
+        writer.print(&buffer)
+    }
+
+    /// Returns the lines of all the tables arranged according to the layout
+    fn output(&self, writer: &BufferWriter) -> Result<Vec<u8>> {
+        let widths = match self.direction {
+            Direction::Horizontal => Vec::new(),
+            Direction::Vertical => self.shared_widths(),
+        };
+
+        let tables = self
+            .tables
+            .iter()
+            .map(|table| lines(table, writer, &widths))
+            .collect::<Result<Vec<_>>>()?;
+
+        let lines = match self.direction {
+            Direction::Horizontal => self.horizontal_lines(&tables),
+            Direction::Vertical => self.vertical_lines(tables),
+        };
+
+        let mut output = Vec::new();
+
+        for line in lines {
+            output.extend_from_slice(line.as_bytes());
+            output.push(b'\n');
+        }
+
+        Ok(output)
+    }
+
+    /// Returns the maximum width of columns at each position in all the tables
+    fn shared_widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = Vec::new();
+
+        for table in self.tables.iter() {
+            for (column, width) in table.required_widths().into_iter().enumerate() {
+                match widths.get_mut(column) {
+                    Some(shared_width) => *shared_width = std::cmp::max(width, *shared_width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        widths
+    }
+
+    fn horizontal_lines(&self, tables: &[Vec<String>]) -> Vec<String> {
+        let height = tables.iter().map(Vec::len).max().unwrap_or_default();
+        let gap = " ".repeat(self.gap);
+
+        let mut lines = vec![String::new(); height];
+
+        for (i, table) in tables.iter().enumerate() {
+            let width = table
+                .iter()
+                .map(|line| display_width(line))
+                .max()
+                .unwrap_or_default();
+
+            let offset = match self.align {
+                Align::Top => 0,
+                Align::Bottom => height - table.len(),
+                Align::Center => (height - table.len()) / 2,
+            };
+
+            let is_last = i + 1 == tables.len();
+
+            for (position, line) in lines.iter_mut().enumerate() {
+                if i > 0 {
+                    line.push_str(&gap);
+                }
+
+                let table_line = position
+                    .checked_sub(offset)
+                    .and_then(|position| table.get(position))
+                    .map(String::as_str)
+                    .unwrap_or_default();
+
+                line.push_str(table_line);
+
+                if !is_last {
+                    line.push_str(&" ".repeat(width - display_width(table_line)));
+                }
+            }
+        }
+
+        lines
+            .into_iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    fn vertical_lines(&self, tables: Vec<Vec<String>>) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for (i, table) in tables.into_iter().enumerate() {
+            if i > 0 {
+                lines.extend(std::iter::repeat_n(String::new(), self.gap));
+            }
+
+            lines.extend(table);
+        }
+
+        lines
+    }
+}
+
+/// Returns the lines of a table printed with given minimum widths of columns
+fn lines(table: &TableStruct, writer: &BufferWriter, widths: &[usize]) -> Result<Vec<String>> {
+    let output: Vec<u8> = table
+        .fitted_buffers(writer, widths)?
+        .iter()
+        .flat_map(|buffer| buffer.as_slice())
+        .copied()
+        .collect();
+
+    let output = String::from_utf8(output).expect("valid utf8 string");
+
+    Ok(output.trim_end().lines().map(ToString::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Table, format::Separator};
+
+    #[test]
+    fn test_horizontal_layout() {
+        let before = vec![vec![1], vec![2]]
+            .table()
+            .title(vec!["Before"])
+            .separator(Separator::builder().build());
+        let after = vec![vec![3]]
+            .table()
+            .separator(Separator::builder().build());
+
+        let layout = TableLayout::horizontal([before, after])
+            .align(Align::Bottom)
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+--------+\n| Before |\n| 1      |  +---+\n| 2      |  | 3 |\n+--------+  +---+",
+            layout.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_vertical_layout() {
+        let first = vec![vec!["a", "b"]]
+            .table()
+            .separator(Separator::builder().build());
+        let second = vec![vec!["ccc", "d"]]
+            .table()
+            .separator(Separator::builder().build());
+
+        let layout = TableLayout::vertical([first, second]).color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+--------+\n| a    b |\n+--------+\n\n+--------+\n| ccc  d |\n+--------+",
+            layout.display().unwrap().to_string()
+        );
+    }
+}
@@ -88,7 +88,7 @@
 //!
 //! ## Derive macro
 //!
-//! `#[derive(Table)]` can also be used to print a `Vec` or slice of `struct`s as table.
+//! `#[derive(Table)]` can also be used to print a `Vec` or slice of `struct`s (or `enum`s) as table.
 //!
 //! ```rust
 //! use cli_table::{format::Justify, print_stdout, Table, WithTitle};
@@ -142,19 +142,399 @@
 //!   be sorted based on their order. For e.g., column with `order = 0` will be displayed on the left followed by
 //!   column with `order = 1` and so on.
 //! - `display_fn`: Used to print types which do not implement `Display` trait. Usage `#[table(display_fn = "<func_name>")]`.
-//!   Signature of provided function should be `fn <func_name>(value: &<type>) -> impl Display`.
+//!   Formatters for common kinds of values (e.g., byte sizes and durations) are available in [`fmt`] module.
+//!   Signature of provided function should be `fn <func_name>(value: &<type>) -> impl Cell`, i.e., it can return any
+//!   type implementing `Display` or a [`CellStruct`] (like the formatters in [`fmt`] module). Value of the attribute
+//!   can be any expression which can be called like this function, e.g., a path (`"fmt::bytes"`, `"Self::format_id"`),
+//!   a generic function (`"with_unit::<f32>"`) or a closure (`"|name: &str| name.to_uppercase()"`).
+//! - `format`: Used to print a field using a format string. Usage `#[table(format = "{:.2}")]`. This is equivalent to
+//!   `display_fn` calling `format!("{:.2}", value)` and cannot be used along with `display_fn`.
 //! - `customize_fn`: Used to customize style of a cell. Usage `#[table(customize_fn = "<func_name>")]`. Signature of
-//!   provided function should be `fn <func_name>(cell: CellStruct, value: &<type>) -> CellStruct`. This attribute can
-//!   be used when you want to change the formatting/style of a cell based on its contents. Note that this will
-//!   overwrite all the style settings done by other attributes.
+//!   provided function should be `fn <func_name>(cell: CellStruct, value: &<type>) -> CellStruct`. Similar to
+//!   `display_fn`, value of the attribute can be a path, a generic function or a closure. This attribute can be used
+//!   when you want to change the formatting/style of a cell based on its contents. Note that this will overwrite all
+//!   the style settings done by other attributes.
+//! - `style_fn`: Used to style a cell based on its value. Usage `#[table(style_fn = "<func_name>")]`. Signature of
+//!   provided function should be `fn <func_name>(value: &<type>) -> StyleStruct`. Returned style is applied on top of
+//!   the style set by other attributes (see [`CellStruct::style`]).
+//! - `skip_if`: Used to skip a column from a table created using `with_title()` when given predicate holds for the
+//!   values of the field in all the rows (e.g., a column which is empty in all the rows). Usage:
+//!   `#[table(skip_if = "Option::is_none")]`. Signature of provided function should be
+//!   `fn <func_name>(value: &<type>) -> bool`. Columns are never skipped from a table without any rows.
+//! - `join`: Used to display the items of a collection (e.g., `Vec<T>` or `BTreeSet<T>`) joined with given separator.
+//!   Usage: `#[table(join = ", ")]`
+//! - `lines`: Used to display the items of a collection on separate lines. Usage: `#[table(lines)]`
+//! - `max_items`: Used along with `join` or `lines` to limit the number of items displayed, followed by the number of
+//!   remaining items (e.g., `+3 more`). Usage: `#[table(join = ", ", max_items = <usize>)]`
+//! - `placeholder`: Used to specify the text displayed for `None` values of a field of type `Option<T>`. Usage:
+//!   `#[table(placeholder = "n/a")]`. Without this attribute, `None` values are displayed using the placeholder of
+//!   the table (see [`TableStruct::null_placeholder`]).
 //! - `skip`: Used to skip a field from table. Usage: `#[table(skip)]`
+//! - `children`: Used to mark a field containing children of a node (e.g., `Vec<Self>`). This field is skipped from
+//!   table and is used to implement [`Tree`] trait for the struct, so that it can be printed using [`TreeTable`].
+//!   Usage: `#[table(children)]`
+//! - `flatten`: Used to splice the columns of a field whose type also derives `Table` into the table of its parent.
+//!   Usage: `#[table(flatten)]`. Titles of the columns can be prefixed using `#[table(flatten, prefix = "stats.")]`
+//!   or grouped under a header spanning all the columns using `#[table(flatten, group = "Stats")]`. Titles keep the
+//!   style of the field's type, except prefixed titles which are styled like other titles of the parent. Other
+//!   attributes of the field (except `order`) and other container attributes of the field's type (e.g., `border`)
+//!   are ignored.
 //!
-//! For more information on configurations available on derive macro, go to `cli-table/examples/struct.rs`.
+//! ```rust
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! struct Stats {
+//!     #[table(title = "CPU")]
+//!     cpu: f32,
+//!     #[table(title = "Memory")]
+//!     mem: u32,
+//! }
+//!
+//! #[derive(Table)]
+//! struct Process {
+//!     #[table(title = "ID")]
+//!     id: u32,
+//!     #[table(flatten, group = "Stats")]
+//!     stats: Stats,
+//! }
+//!
+//! let processes = vec![Process { id: 1, stats: Stats { cpu: 12.5, mem: 1024 } }];
+//! let table = processes.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +----+---------------+
+//! |    |     Stats     |
+//! +----+------+--------+
+//! | ID | CPU  | Memory |
+//! +----+------+--------+
+//! | 1  | 12.5 | 1024   |
+//! +----+------+--------+"
+//! );
+//! ```
+//!
+//! ```rust
+//! use std::collections::BTreeSet;
+//!
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! struct Server {
+//!     #[table(title = "Name")]
+//!     name: &'static str,
+//!     #[table(title = "Tags", join = ", ", max_items = 2)]
+//!     tags: Vec<&'static str>,
+//!     #[table(title = "Ports", lines)]
+//!     ports: BTreeSet<u16>,
+//! }
+//!
+//! let servers = vec![Server {
+//!     name: "web",
+//!     tags: vec!["prod", "eu", "frontend"],
+//!     ports: BTreeSet::from([443, 80]),
+//! }];
+//! let table = servers.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +------+-------------------+-------+
+//! | Name | Tags              | Ports |
+//! +------+-------------------+-------+
+//! | web  | prod, eu, +1 more | 80    |
+//! |      |                   | 443   |
+//! +------+-------------------+-------+"
+//! );
+//! ```
+//!
+//! Fields of type `Option<T>` are displayed like fields of type `T` (`display_fn` receives the whole `Option` though),
+//! with `None` values displayed as missing values.
+//!
+//! ```rust
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! struct Reading {
+//!     #[table(title = "Sensor")]
+//!     sensor: &'static str,
+//!     #[table(title = "Value", format = "{:.1}")]
+//!     value: Option<f64>,
+//!     #[table(title = "Unit", placeholder = "-")]
+//!     unit: Option<&'static str>,
+//! }
+//!
+//! let readings = vec![
+//!     Reading { sensor: "t1", value: Some(21.55), unit: Some("°C") },
+//!     Reading { sensor: "t2", value: None, unit: None },
+//! ];
+//!
+//! let table = readings
+//!     .with_title()
+//!     .null_placeholder("n/a")
+//!     .color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +--------+-------+------+
+//! | Sensor | Value | Unit |
+//! +--------+-------+------+
+//! | t1     | 21.6  | °C   |
+//! +--------+-------+------+
+//! | t2     | n/a   | -    |
+//! +--------+-------+------+"
+//! );
+//! ```
+//!
+//! ```rust
+//! use cli_table::{
+//!     Color, ColorChoice, Style, StyleStruct, Table, WithTitle,
+//! };
+//!
+//! fn status_style(status: &&str) -> StyleStruct {
+//!     let color = if *status == "failed" { Some(Color::Red) } else { None };
+//!     StyleStruct::default().foreground_color(color)
+//! }
+//!
+//! #[derive(Table)]
+//! struct Job {
+//!     #[table(title = "Name")]
+//!     name: &'static str,
+//!     #[table(title = "Status", style_fn = "status_style")]
+//!     status: &'static str,
+//!     #[table(title = "Error", skip_if = "Option::is_none")]
+//!     error: Option<&'static str>,
+//! }
+//!
+//! let jobs = vec![
+//!     Job { name: "build", status: "passed", error: None },
+//!     Job { name: "test", status: "failed", error: None },
+//! ];
+//! let table = jobs.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +-------+--------+
+//! | Name  | Status |
+//! +-------+--------+
+//! | build | passed |
+//! +-------+--------+
+//! | test  | failed |
+//! +-------+--------+"
+//! );
+//! ```
+//!
+//! ### Container attributes
+//!
+//! - `crate`: Used to specify path of `cli_table` crate. Usage: `#[table(crate = "path::to::cli_table")]`
+//! - `index`: Used to add an index column containing row numbers (starting from 1) at the left of a table created
+//!   using `with_title()`. Usage: `#[table(index)]` or `#[table(index = <usize>)]` to start from a different number.
+//! - `kind_title`: Used to specify the title of first column of enums containing names of variants (`Kind` by
+//!   default). Usage: `#[table(kind_title = "Type")]`
+//! - `rename_all`: Used to convert the default titles of columns (names of fields and variants) to given case. Usage:
+//!   `#[table(rename_all = "Title Case")]`. Supported values are `"lower"`, `"UPPER"`, `"Title Case"`,
+//!   `"Sentence case"`, `"snake_case"` and `"kebab-case"`.
+//! - `justify`, `align`, `color`: Used to specify defaults for field attributes with same names, which are applied
+//!   to all the fields which do not override them. Usage: `#[table(justify = "Justify::Right")]`
+//! - `title_bold`, `title_color`, `title_background`: Used to specify style of title row (bold by default). Usage:
+//!   `#[table(title_bold = false, title_color = "Color::Blue")]`
+//! - `border`, `separator`: Used to specify border and separators of a table created using `with_title()`. Usage:
+//!   `#[table(border = "<expr>", separator = "<expr>")]`, where expressions evaluate to [`format::Border`] and
+//!   [`format::Separator`] respectively.
+//! - `doc_comments`: Used to take the titles of columns from the first lines of doc comments of fields which do not
+//!   have `title` attribute. Full text of doc comments is available as descriptions of columns in [`Schema`]. On
+//!   enums, doc comments of fields of variants are used in the same way (description of a column shared by multiple
+//!   variants is taken from the first of those fields), while doc comments of variants themselves are ignored. Usage:
+//!   `#[table(doc_comments)]`
+//!
+//! ```rust
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! #[table(
+//!     rename_all = "Title Case",
+//!     justify = "cli_table::format::Justify::Right",
+//!     title_bold = false,
+//!     border = "cli_table::format::Border::builder().build()",
+//!     separator = "cli_table::format::Separator::builder().build()"
+//! )]
+//! struct User {
+//!     user_id: u64,
+//!     #[table(title = "Name", justify = "cli_table::format::Justify::Left")]
+//!     first_name: &'static str,
+//! }
+//!
+//! let users = vec![User { user_id: 1, first_name: "Scooby" }];
+//! let table = users.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "User Id  Name   \n       1  Scooby"
+//! );
+//! ```
+//!
+//! ### Enums
+//!
+//! `#[derive(Table)]` can also be used on `enum`s. First column (`Kind`) of the table contains the name of variant
+//! followed by a column for each field of all the variants (fields of different variants with same title share a
+//! column). Cells of fields which are not present in a variant are left blank. Title of the first column can be
+//! changed using `#[table(kind_title = "Type")]` container attribute.
+//!
+//! ```rust
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! enum Shape {
+//!     Circle {
+//!         #[table(title = "Width")]
+//!         radius: u32,
+//!     },
+//!     #[table(title = "Rect")]
+//!     Rectangle(
+//!         #[table(title = "Width")] u32,
+//!         #[table(title = "Height")] u32,
+//!     ),
+//!     Point,
+//! }
+//!
+//! let shapes = vec![Shape::Circle { radius: 2 }, Shape::Rectangle(3, 4), Shape::Point];
+//! let table = shapes.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +--------+-------+--------+
+//! | Kind   | Width | Height |
+//! +--------+-------+--------+
+//! | Circle | 2     |        |
+//! +--------+-------+--------+
+//! | Rect   | 3     | 4      |
+//! +--------+-------+--------+
+//! | Point  |       |        |
+//! +--------+-------+--------+"
+//! );
+//! ```
+//!
+//! ```rust
+//! use cli_table::{ColorChoice, Table, WithTitle};
+//!
+//! #[derive(Table)]
+//! #[table(kind_title = "Event")]
+//! enum Never {}
+//!
+//! let events: Vec<Never> = Vec::new();
+//! let table = events.with_title().color_choice(ColorChoice::Never);
+//!
+//! assert_eq!(
+//!     table.display().unwrap().to_string(),
+//!     "\
+//! +-------+
+//! | Event |
+//! +-------+"
+//! );
+//! ```
+//!
+//! ### Variant attributes
+//!
+//! - `title` | `name`: Used to specify name of a variant displayed in `Kind` column. Usage: `#[table(title = "Title")]`
+//! - `color`: Used to specify color of contents of all the cells of a variant's row. Usage:
+//!   `#[table(color = "Color::Red")]`
+//! - `bold`: Used to specify boldness of contents of all the cells of a variant's row. Usage: `#[table(bold)]`
+//!
+//! All the field attributes (except `children`, `flatten` and `skip_if`) can be used on fields of variants.
+//!
+//! ### Column metadata
+//!
+//! Derive macro also implements [`Schema`] trait, which returns metadata of the columns of a table (title, name of
+//! the field, position, justification, whether the field is numeric and description taken from doc comments). This
+//! can be used to generate help text and validate values of command line options referring to columns (e.g.,
+//! `--sort-by`).
+//!
+//! ```rust
+//! use cli_table::{format::Justify, Schema, Table};
+//!
+//! #[derive(Table)]
+//! struct User {
+//!     #[table(title = "Name")]
+//!     name: &'static str,
+//!     #[table(title = "Age", justify = "Justify::Right")]
+//!     age: Option<u8>,
+//! }
+//!
+//! let columns = User::columns();
+//! let names: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+//!
+//! assert_eq!(names, ["Name", "Age"]);
+//! assert_eq!(columns[1].field.as_deref(), Some("age"));
+//! assert_eq!(columns[1].justify, Justify::Right);
+//! assert!(!columns[0].numeric && columns[1].numeric);
+//! assert_eq!(columns[1].order, 1);
+//! assert_eq!(User::column_index("age"), Some(1));
+//! ```
+//!
+//! [`Schema::columns`] and [`Schema::column_index`] describe all the columns of a table, including those skipped using
+//! `skip_if`. [`Schema::visible_columns`] returns only the columns displayed for given rows.
+//!
+//! ```rust
+//! use cli_table::{Schema, Table};
+//!
+//! #[derive(Table)]
+//! struct Process {
+//!     #[table(title = "Name")]
+//!     name: &'static str,
+//!     #[table(title = "GPU", skip_if = "Option::is_none")]
+//!     gpu: Option<u8>,
+//!     #[table(title = "ID")]
+//!     id: u32,
+//! }
+//!
+//! let processes = [Process { name: "init", gpu: None, id: 1 }];
+//! let rows: Vec<&Process> = processes.iter().collect();
+//! let columns = Process::visible_columns(&rows);
+//!
+//! assert_eq!(Process::columns().len(), 3);
+//! assert_eq!(columns.len(), 2);
+//! assert_eq!(columns[1].name, "ID");
+//! assert_eq!(columns[1].order, 2);
+//! ```
+//!
+//! ```rust
+//! use cli_table::{Schema, Table};
+//!
+//! #[derive(Table)]
+//! #[table(doc_comments)]
+//! struct Disk {
+//!     /// Mount point
+//!     ///
+//!     /// Path where the file system is mounted.
+//!     path: &'static str,
+//!     /// Usage
+//!     #[table(title = "Used")]
+//!     used: f32,
+//! }
+//!
+//! let columns = Disk::columns();
+//!
+//! assert_eq!(columns[0].name, "Mount point");
+//! assert_eq!(
+//!     columns[0].description.as_deref(),
+//!     Some("Mount point\n\nPath where the file system is mounted.")
+//! );
+//! assert_eq!(columns[1].name, "Used");
+//! assert_eq!(columns[1].description.as_deref(), Some("Usage"));
+//! ```
+//!
+//! For more information on configurations available on derive macro, go to `cli-table/examples/struct.rs`,
+//! `cli-table/examples/display_fn.rs` and `cli-table/examples/enum.rs`.
 //!
 //! ## CSV
 //!
 //! This crate also integrates with [`csv`](https://crates.io/crates/csv) crate. On enabling `"csv"` feature, you can
-//! use `TryFrom<&mut Reader> for TableStruct` trait implementation to convert `csv::Reader` to `TableStruct`.
+//! use `TryFrom<&mut Reader> for TableStruct` trait implementation to convert `csv::Reader` to `TableStruct`. Empty
+//! fields are converted into cells for missing values (see [`TableStruct::null_placeholder`]).
 //!
 //! For more information on handling CSV values, go to `cli-table/examples/csv.rs`.
 //!
@@ -189,18 +569,29 @@
 //!
 //! - `derive`: Enables derive macro for creating tables using structs. **Enabled** by default.
 //! - `csv`: Enables support for printing tables using [`csv`](https://crates.io/crates/csv). **Enabled** by default.
+//! - `regex`: Enables conditional formatting of cells matching a [`regex`](https://crates.io/crates/regex)
+//!   (`TableStruct::style_match`). **Disabled** by default.
 mod buffers;
 mod cell;
+mod chart;
 #[cfg(feature = "csv")]
 mod csv;
 mod display;
+mod layout;
+mod pager;
 mod row;
+mod rule;
+#[cfg(any(feature = "title", feature = "derive"))]
+mod schema;
 mod style;
 mod table;
 #[cfg(any(feature = "title", feature = "derive"))]
 mod title;
+mod tree;
 mod utils;
+mod view;
 
+pub mod fmt;
 pub mod format;
 
 pub use termcolor::{Color, ColorChoice};
@@ -211,14 +602,20 @@ pub use cli_table_derive::Table;
 
 pub use self::{
     cell::{Cell, CellStruct},
+    chart::{Bar, Sparkline},
     display::TableDisplay,
+    layout::TableLayout,
     row::{Row, RowStruct},
-    style::Style,
+    style::{Style, StyleStruct},
     table::{Table, TableStruct},
+    tree::{Tree, TreeNode, TreeTable},
 };
 
 #[cfg(any(feature = "title", feature = "derive"))]
-pub use self::title::{Title, WithTitle};
+pub use self::{
+    schema::{Column, Schema},
+    title::{Title, WithTitle},
+};
 
 use std::io::Result;
 
@@ -227,6 +624,14 @@ pub fn print_stdout<T: Table>(table: T) -> Result<()> {
     table.table().print_stdout()
 }
 
+/// Prints a table to `stdout` through a pager (`$PAGER` or `less -FRSX`) when it is taller than the terminal
+///
+/// Pager command is run using the shell (`sh -c` or `cmd /C` on Windows). When `stdout` is not a terminal or the pager
+/// cannot be started, the table is printed directly to `stdout`.
+pub fn print_paged<T: Table>(table: T) -> Result<()> {
+    table.table().print_paged()
+}
+
 /// Prints a table to `stderr`
 pub fn print_stderr<T: Table>(table: T) -> Result<()> {
     table.table().print_stderr()
@@ -0,0 +1,64 @@
+use std::{
+    io::{ErrorKind, Result, Write},
+    process::{Command, Stdio},
+};
+
+/// Pager used when `PAGER` environment variable is not set
+pub(crate) const DEFAULT_PAGER: &str = "less -FRSX";
+
+/// Returns the pager command from `PAGER` environment variable (or the default pager if it is not set)
+pub(crate) fn pager() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_PAGER.to_string())
+}
+
+/// Returns the height of the terminal attached to `stdout` (if any)
+pub(crate) fn terminal_height() -> Option<usize> {
+    terminal_size::terminal_size().map(|(_, terminal_size::Height(height))| height.into())
+}
+
+/// Pipes the output into given pager command and waits for it to exit
+///
+/// Pager command is run using the shell (like `git` does), so it can contain quoted arguments and paths with spaces.
+/// Returns `Ok(false)` if the pager could not be started.
+pub(crate) fn page(output: &[u8], pager: &str) -> Result<bool> {
+    if pager.trim().is_empty() {
+        return Ok(false);
+    }
+
+    let mut child = match shell(pager).stdin(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(_) => return Ok(false),
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(output) {
+            // Pager exited before reading the whole output (e.g., user quit the pager)
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => {}
+            result => result?,
+        }
+    }
+
+    let status = child.wait()?;
+
+    // Shell exits with 127 when the pager command is not found
+    Ok(status.code() != Some(127))
+}
+
+/// Returns a command which runs given command line using the shell
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+/// Returns a command which runs given command line using the shell
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
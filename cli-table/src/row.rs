@@ -1,4 +1,4 @@
-use std::io::Result;
+use std::{io::Result, ops::Range};
 
 use termcolor::{Buffer, BufferWriter, ColorSpec};
 
@@ -10,11 +10,71 @@ use crate::{
 };
 
 /// Concrete row of a table
+#[derive(Clone)]
 pub struct RowStruct {
     pub(crate) cells: Vec<CellStruct>,
 }
 
 impl RowStruct {
+    /// Returns the cells in the row
+    pub fn cells(&self) -> &[CellStruct] {
+        &self.cells
+    }
+
+    /// Returns a row containing only given columns of current row
+    ///
+    /// Cells spanning multiple columns are kept once, spanning the given columns they occupied.
+    pub(crate) fn project(&self, columns: &[usize]) -> Self {
+        let owners: Vec<usize> = self
+            .cells
+            .iter()
+            .enumerate()
+            .flat_map(|(index, cell)| std::iter::repeat_n(index, cell.columns()))
+            .collect();
+
+        let mut cells: Vec<CellStruct> = Vec::with_capacity(columns.len());
+        let mut last_owner = None;
+
+        for owner in columns.iter().filter_map(|column| owners.get(*column)) {
+            match cells.last_mut() {
+                Some(cell) if last_owner == Some(*owner) => cell.format.span = cell.columns() + 1,
+                _ => cells.push(self.cells[*owner].clone().span(1)),
+            }
+
+            last_owner = Some(*owner);
+        }
+
+        Self { cells }
+    }
+
+    /// Returns `true` if any cell of the row spans multiple columns
+    pub(crate) fn is_spanned(&self) -> bool {
+        self.cells.iter().any(|cell| cell.columns() > 1)
+    }
+
+    /// Returns the ranges of columns occupied by each cell of the row
+    fn column_ranges(&self) -> Vec<Range<usize>> {
+        let mut start = 0;
+
+        self.cells
+            .iter()
+            .map(|cell| {
+                let range = start..start + cell.columns();
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the row is separated between given column and the next one (i.e., no cell of the row spans
+    /// both the columns)
+    pub(crate) fn is_separated(&self, column: usize) -> bool {
+        !self
+            .column_ranges()
+            .iter()
+            .any(|range| range.contains(&column) && range.contains(&(column + 1)))
+    }
+
     pub(crate) fn required_dimension(&self) -> Dimension {
         let mut widths = Vec::with_capacity(self.cells.len());
         let mut height = 0;
@@ -22,7 +82,11 @@ impl RowStruct {
         for cell in self.cells.iter() {
             let cell_dimension = cell.required_dimension();
 
-            widths.push(cell_dimension.width);
+            // Widths of spanning cells are fitted separately (see `fit_spans`)
+            match cell.columns() {
+                1 => widths.push(cell_dimension.width),
+                columns => widths.extend(std::iter::repeat_n(0, columns)),
+            }
 
             height = std::cmp::max(cell_dimension.height, height);
         }
@@ -30,6 +94,26 @@ impl RowStruct {
         Dimension { widths, height }
     }
 
+    /// Widens the last column spanned by each spanning cell of the row (if required) to fit its contents
+    pub(crate) fn fit_spans(&self, widths: &mut Vec<usize>, format: &TableFormat) {
+        for (cell, range) in self.cells.iter().zip(self.column_ranges()) {
+            if cell.columns() == 1 {
+                continue;
+            }
+
+            if widths.len() < range.end {
+                widths.resize(range.end, 0);
+            }
+
+            let required_width = cell.required_dimension().width;
+            let available_width = span_width(widths, range.clone(), format);
+
+            if required_width > available_width {
+                widths[range.end - 1] += required_width - available_width;
+            }
+        }
+    }
+
     pub(crate) fn buffers(
         &self,
         writer: &BufferWriter,
@@ -37,12 +121,24 @@ impl RowStruct {
         format: &TableFormat,
         color_spec: &ColorSpec,
     ) -> Result<Vec<Buffer>> {
-        let available_cell_dimensions: Vec<CellDimension> = available_dimension.into();
+        let column_ranges = self.column_ranges();
+
+        let available_cell_dimensions: Vec<CellDimension> = if self.is_spanned() {
+            column_ranges
+                .iter()
+                .map(|range| CellDimension {
+                    width: span_width(&available_dimension.widths, range.clone(), format),
+                    height: available_dimension.height,
+                })
+                .collect()
+        } else {
+            available_dimension.into()
+        };
 
         let cell_buffers = self
             .cells
             .iter()
-            .zip(available_cell_dimensions.into_iter())
+            .zip(available_cell_dimensions)
             .map(|(cell, available_dimension)| cell.buffers(writer, available_dimension))
             .collect::<Result<Vec<Vec<Buffer>>>>()?;
 
@@ -53,17 +149,15 @@ impl RowStruct {
         for line in cell_buffers {
             print_vertical_line(&mut buffers, format.border.left.as_ref(), color_spec)?;
 
-            let mut line_buffers = line.into_iter().peekable();
+            let mut line_buffers = line.into_iter().enumerate().peekable();
 
-            while let Some(line_buffer) = line_buffers.next() {
-                print_char(&mut buffers, ' ', color_spec)?;
-                buffers.push(line_buffer)?;
-                print_char(&mut buffers, ' ', color_spec)?;
+            while let Some((index, line_buffer)) = line_buffers.next() {
+                print_cell_line(&mut buffers, &self.cells[index], line_buffer, color_spec)?;
 
                 match line_buffers.peek() {
                     Some(_) => print_vertical_line(
                         &mut buffers,
-                        format.separator.column.as_ref(),
+                        format.column_separator(column_ranges[index].end - 1),
                         color_spec,
                     )?,
                     None => {
@@ -79,6 +173,58 @@ impl RowStruct {
     }
 }
 
+/// Returns the width available for a cell spanning given columns (including the separators between them)
+fn span_width(widths: &[usize], columns: Range<usize>, format: &TableFormat) -> usize {
+    let separators: usize = (columns.start..columns.end.saturating_sub(1))
+        .map(|column| 2 + usize::from(format.column_separator(column).is_some()))
+        .sum();
+
+    widths
+        .get(columns)
+        .unwrap_or_default()
+        .iter()
+        .sum::<usize>()
+        + separators
+}
+
+/// Returns the buffers for a single cell spanning the full width of a table
+pub(crate) fn span_buffers(
+    cell: &CellStruct,
+    writer: &BufferWriter,
+    available_dimension: CellDimension,
+    format: &TableFormat,
+    color_spec: &ColorSpec,
+) -> Result<Vec<Buffer>> {
+    let cell_buffers = cell.buffers(writer, available_dimension)?;
+
+    let mut buffers = Buffers::new(writer);
+
+    for line_buffer in cell_buffers {
+        print_vertical_line(&mut buffers, format.border.left.as_ref(), color_spec)?;
+        print_cell_line(&mut buffers, cell, line_buffer, color_spec)?;
+        print_vertical_line(&mut buffers, format.border.right.as_ref(), color_spec)?;
+        println(&mut buffers)?;
+    }
+
+    buffers.into_vec()
+}
+
+/// Prints a line of a cell surrounded by spaces (unless the cell contains a nested table with merged border)
+fn print_cell_line(
+    buffers: &mut Buffers<'_>,
+    cell: &CellStruct,
+    line_buffer: Buffer,
+    color_spec: &ColorSpec,
+) -> Result<()> {
+    if cell.is_merged() {
+        return buffers.push(line_buffer);
+    }
+
+    print_char(buffers, ' ', color_spec)?;
+    buffers.push(line_buffer)?;
+    print_char(buffers, ' ', color_spec)
+}
+
 /// Trait to convert raw types into rows
 pub trait Row {
     /// Converts raw type to rows of a table
@@ -0,0 +1,231 @@
+use termcolor::Color;
+
+use crate::{
+    cell::CellStruct,
+    row::RowStruct,
+    style::{Style, StyleStruct},
+    view::BodyRow,
+};
+
+/// Predicate used for conditionally styling cells of a table
+pub(crate) type CellPredicate = Box<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// Rule for conditionally styling the cells of a column
+pub(crate) enum Rule {
+    /// Applies a style to the cells whose contents satisfy the predicate
+    Predicate {
+        column: usize,
+        predicate: CellPredicate,
+        style: StyleStruct,
+    },
+    /// Applies the style of the largest threshold not greater than the numeric value of a cell (thresholds are sorted
+    /// in ascending order)
+    Threshold {
+        column: usize,
+        thresholds: Vec<(f64, StyleStruct)>,
+    },
+    /// Interpolates the background color of cells between two colors based on their numeric value in the range of
+    /// values in the column
+    Heatmap {
+        column: usize,
+        low: (u8, u8, u8),
+        high: (u8, u8, u8),
+    },
+}
+
+impl Rule {
+    fn column(&self) -> usize {
+        match self {
+            Rule::Predicate { column, .. }
+            | Rule::Threshold { column, .. }
+            | Rule::Heatmap { column, .. } => *column,
+        }
+    }
+
+    /// Applies the rule to the cells of its column in the rows of a table (computed rows, e.g., subtotals, are not
+    /// styled)
+    ///
+    /// Range of values for heatmaps is computed from given rows (i.e., all the rows which satisfy the filters of the
+    /// table), so that colors do not change when some of the rows are hidden by elision.
+    pub(crate) fn apply(&self, body: &mut [BodyRow<'_>], rows: &[&RowStruct]) {
+        let column = self.column();
+
+        let range = match self {
+            Rule::Heatmap { .. } => range(rows, column),
+            _ => None,
+        };
+
+        for body_row in body.iter_mut() {
+            if let BodyRow::Row(Some(_), row) = body_row
+                && let Some(cell) = row.cells.get(column)
+                && let Some(style) = self.style(cell, range)
+            {
+                let cell = &mut row.to_mut().cells[column];
+                cell.style = cell.style.overlay(style);
+            }
+        }
+    }
+
+    /// Returns the style to be applied to a cell (if any)
+    fn style(&self, cell: &CellStruct, range: Option<(f64, f64)>) -> Option<StyleStruct> {
+        match self {
+            Rule::Predicate {
+                predicate, style, ..
+            } => predicate(&cell.content()).then_some(*style),
+            Rule::Threshold { thresholds, .. } => {
+                let value = parse_number(&cell.content())?;
+
+                thresholds
+                    .iter()
+                    .rev()
+                    .find(|(threshold, _)| value >= *threshold)
+                    .map(|(_, style)| *style)
+            }
+            Rule::Heatmap { low, high, .. } => {
+                let value = parse_number(&cell.content())?;
+                let (min, max) = range?;
+
+                let ratio = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+
+                let color = Color::Rgb(
+                    interpolate(low.0, high.0, ratio),
+                    interpolate(low.1, high.1, ratio),
+                    interpolate(low.2, high.2, ratio),
+                );
+
+                Some(StyleStruct::default().background_color(Some(color)))
+            }
+        }
+    }
+}
+
+/// Returns the minimum and maximum numeric values in a column of given rows
+fn range(rows: &[&RowStruct], column: usize) -> Option<(f64, f64)> {
+    rows.iter()
+        .filter_map(|row| row.cells.get(column))
+        .filter_map(|cell| parse_number(&cell.content()))
+        .fold(None, |range, value| match range {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        })
+}
+
+/// Parses the contents of a cell as a finite number, ignoring digit separators and a trailing `%`
+///
+/// Texts like `inf` and `NaN` are not considered numbers.
+fn parse_number(content: &str) -> Option<f64> {
+    let content = content.trim().replace([',', '_'], "");
+
+    content
+        .trim_end_matches('%')
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+fn interpolate(low: u8, high: u8, ratio: f64) -> u8 {
+    (low as f64 + (high as f64 - low as f64) * ratio).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        Cell, Row,
+        view::{Elision, View},
+    };
+
+    fn styles(rule: Rule, values: &[&str]) -> Vec<StyleStruct> {
+        styles_with(View::default(), rule, values)
+    }
+
+    fn styles_with(view: View, rule: Rule, values: &[&str]) -> Vec<StyleStruct> {
+        let rows: Vec<RowStruct> = values
+            .iter()
+            .map(|value| vec![value.cell()].row())
+            .collect();
+        let view = View {
+            rules: vec![rule],
+            ..view
+        };
+
+        view.body(&rows)
+            .iter()
+            .filter_map(|body_row| match body_row {
+                BodyRow::Row(_, row) => Some(row.cells[0].style),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_threshold() {
+        let yellow = StyleStruct::default().foreground_color(Some(Color::Yellow));
+        let red = StyleStruct::default().foreground_color(Some(Color::Red));
+
+        let rule = Rule::Threshold {
+            column: 0,
+            thresholds: vec![(50.0, yellow), (80.0, red)],
+        };
+
+        assert_eq!(
+            vec![
+                StyleStruct::default(),
+                yellow,
+                red,
+                StyleStruct::default(),
+                StyleStruct::default(),
+            ],
+            styles(rule, &["12%", "50%", "93.5%", "n/a", "inf"])
+        );
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let rule = Rule::Heatmap {
+            column: 0,
+            low: (0, 0, 0),
+            high: (200, 100, 0),
+        };
+
+        let backgrounds: Vec<Option<Color>> = styles(rule, &["10", "20", "30"])
+            .iter()
+            .map(|style| style.background_color)
+            .collect();
+
+        assert_eq!(
+            vec![
+                Some(Color::Rgb(0, 0, 0)),
+                Some(Color::Rgb(100, 50, 0)),
+                Some(Color::Rgb(200, 100, 0)),
+            ],
+            backgrounds
+        );
+    }
+
+    #[test]
+    fn test_heatmap_with_elision() {
+        let rule = Rule::Heatmap {
+            column: 0,
+            low: (0, 0, 0),
+            high: (200, 100, 0),
+        };
+        let view = View {
+            elision: Some(Elision::Tail(1)),
+            ..Default::default()
+        };
+
+        let backgrounds: Vec<Option<Color>> = styles_with(view, rule, &["10", "20", "30"])
+            .iter()
+            .map(|style| style.background_color)
+            .collect();
+
+        assert_eq!(vec![Some(Color::Rgb(200, 100, 0))], backgrounds);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{cell::Justify, title::Title};
+
+/// Metadata of a column of a table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Column {
+    /// Title of the column
+    pub name: String,
+    /// Identifier of the field displayed in the column (e.g., `id`, `0` for tuple structs or `stats.cpu` for fields
+    /// of flattened structs). This is `None` for columns which do not correspond to a field (e.g., `Kind` column of
+    /// enums).
+    pub field: Option<String>,
+    /// Position of the column in [`Schema::columns`], i.e., in a table before any columns are skipped (excluding
+    /// index column)
+    pub order: usize,
+    /// Horizontal justification of the contents of the column set using `justify` attribute (of the field or the
+    /// container)
+    ///
+    /// Without the attribute, this is the default justification even though cells returned by `display_fn` or
+    /// `customize_fn` may be justified differently (e.g., formatters in [`fmt`](crate::fmt) module return
+    /// right-justified cells).
+    pub justify: Justify,
+    /// Whether the field displayed in the column has a numeric type (e.g., `u32`, `f64` or `Option<i64>`)
+    ///
+    /// This is `false` for fields displayed using `display_fn` or `format`, as their displayed values may not be
+    /// numbers (e.g., `1.2 GiB`).
+    pub numeric: bool,
+    /// Description of the column (doc comment of the field when `#[table(doc_comments)]` is set on the container)
+    pub description: Option<String>,
+}
+
+impl Column {
+    /// Creates metadata of a column with given title which does not correspond to a field
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            field: None,
+            order: 0,
+            justify: Justify::default(),
+            numeric: false,
+            description: None,
+        }
+    }
+}
+
+/// Trait for getting metadata of the columns of a table created using [`WithTitle::with_title`](crate::WithTitle)
+///
+/// This is implemented by `#[derive(Table)]` and can be used to generate help text and validation for command line
+/// options referring to columns (e.g., `--columns` or `--sort-by`).
+#[cfg_attr(
+    any(docsrs, feature = "doc"),
+    doc(cfg(any(feature = "title", feature = "derive")))
+)]
+pub trait Schema {
+    /// Returns metadata of all the columns of a table, in the order in which they are displayed (excluding index
+    /// column)
+    ///
+    /// Columns skipped using `skip_if` are included. Use [`Schema::visible_columns`] for the columns displayed for
+    /// given rows.
+    fn columns() -> Vec<Column>;
+
+    /// Returns the position of a column with given name or field identifier in [`Schema::columns`]
+    ///
+    /// This is the position of the column before any columns are skipped using `skip_if`.
+    fn column_index(name: &str) -> Option<usize> {
+        Self::columns()
+            .iter()
+            .position(|column| column.name == name || column.field.as_deref() == Some(name))
+    }
+
+    /// Returns metadata of the columns displayed in a table created using [`WithTitle::with_title`](crate::WithTitle)
+    /// from given rows (i.e., excluding columns skipped using `skip_if`)
+    fn visible_columns(rows: &[&Self]) -> Vec<Column>
+    where
+        Self: Title + Sized,
+    {
+        let columns = Self::columns();
+
+        if !Self::SKIPS_COLUMNS {
+            return columns;
+        }
+
+        let skipped = Self::skipped_columns(rows);
+
+        columns
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !skipped.contains(index))
+            .map(|(_, column)| column)
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+
+    #[derive(crate::Table)]
+    #[table(crate = "crate")]
+    struct Usage {
+        #[table(title = "CPU")]
+        cpu: u8,
+        #[table(title = "GPU", skip_if = "Option::is_none")]
+        gpu: Option<u8>,
+    }
+
+    #[derive(crate::Table)]
+    #[table(crate = "crate")]
+    struct Process {
+        #[table(title = "ID", justify = "Justify::Right")]
+        id: u32,
+        #[table(title = "Memory", display_fn = "crate::fmt::bytes")]
+        memory: u64,
+        #[table(title = "Load", format = "{:.2}%")]
+        load: f32,
+        #[table(flatten, prefix = "Usage ")]
+        usage: Usage,
+    }
+
+    /// Event
+    #[allow(dead_code)]
+    #[derive(crate::Table)]
+    #[table(crate = "crate", doc_comments)]
+    enum Event {
+        /// Started
+        Start {
+            /// Time
+            ///
+            /// Seconds since boot.
+            time: u64,
+        },
+        Stop {
+            /// Stop time
+            time: u64,
+        },
+    }
+
+    fn names(columns: &[Column]) -> Vec<&str> {
+        columns.iter().map(|column| column.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_columns() {
+        let columns = Process::columns();
+
+        assert_eq!(
+            names(&columns),
+            ["ID", "Memory", "Load", "Usage CPU", "Usage GPU"]
+        );
+        assert_eq!(columns[0].justify, Justify::Right);
+        assert_eq!(columns[4].field.as_deref(), Some("usage.gpu"));
+        assert_eq!(columns[4].order, 4);
+        assert_eq!(Process::column_index("usage.gpu"), Some(4));
+
+        // Values displayed using `display_fn` or `format` are not considered numeric
+        let numeric: Vec<bool> = columns.iter().map(|column| column.numeric).collect();
+        assert_eq!(numeric, [true, false, false, true, true]);
+    }
+
+    #[test]
+    fn test_visible_columns() {
+        let processes = [
+            Process {
+                id: 1,
+                memory: 1024,
+                load: 0.5,
+                usage: Usage { cpu: 12, gpu: None },
+            },
+            Process {
+                id: 2,
+                memory: 2048,
+                load: 1.5,
+                usage: Usage { cpu: 3, gpu: None },
+            },
+        ];
+        let rows: Vec<&Process> = processes.iter().collect();
+
+        assert_eq!(
+            names(&Process::visible_columns(&rows)),
+            ["ID", "Memory", "Load", "Usage CPU"]
+        );
+        assert_eq!(Process::visible_columns(&[]).len(), 5);
+    }
+
+    #[test]
+    fn test_variant_doc_comments() {
+        let columns = Event::columns();
+
+        assert_eq!(names(&columns), ["Kind", "Time", "Stop time"]);
+        assert_eq!(columns[0].description, None);
+        assert_eq!(
+            columns[1].description.as_deref(),
+            Some("Time\n\nSeconds since boot.")
+        );
+        assert_eq!(columns[2].field.as_deref(), Some("time"));
+    }
+}
@@ -18,8 +18,11 @@ pub trait Style {
     fn dimmed(self, dimmed: bool) -> Self;
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-pub(crate) struct StyleStruct {
+/// Concrete style of a table or cell
+///
+/// A default style can be created using `StyleStruct::default()` and modified using functions of [`Style`] trait.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StyleStruct {
     pub(crate) foreground_color: Option<Color>,
     pub(crate) background_color: Option<Color>,
     pub(crate) bold: bool,
@@ -30,6 +33,19 @@ pub(crate) struct StyleStruct {
 }
 
 impl StyleStruct {
+    /// Returns current style with the colors and attributes set in given style applied on top of it
+    pub(crate) fn overlay(self, other: StyleStruct) -> Self {
+        Self {
+            foreground_color: other.foreground_color.or(self.foreground_color),
+            background_color: other.background_color.or(self.background_color),
+            bold: self.bold || other.bold,
+            underline: self.underline || other.underline,
+            italic: self.italic || other.italic,
+            intense: self.intense || other.intense,
+            dimmed: self.dimmed || other.dimmed,
+        }
+    }
+
     pub(crate) fn color_spec(&self) -> ColorSpec {
         let mut color_spec = ColorSpec::new();
 
@@ -1,19 +1,36 @@
-use std::io::Result;
+use std::{
+    borrow::Cow,
+    io::{IsTerminal, Result, Write},
+    ops::Range,
+    sync::{Arc, OnceLock},
+};
 
 use termcolor::{Buffer, BufferWriter, Color, ColorChoice, ColorSpec};
 
 use crate::{
     buffers::Buffers,
+    cell::{Cell, CellStruct, Dimension as CellDimension, Justify},
     display::TableDisplay,
-    row::{Dimension as RowDimension, Row, RowStruct},
+    pager::{page, pager, terminal_height},
+    row::{Dimension as RowDimension, Row, RowStruct, span_buffers},
+    rule::Rule,
     style::{Style, StyleStruct},
     utils::*,
+    view::{BodyRow, Elision, Grouping, Index, View},
 };
 
 /// Struct for building a table on command line
 pub struct TableStruct {
+    /// Caption of the table
+    caption: Option<CellStruct>,
+    /// Position of the caption of the table
+    caption_position: CaptionPosition,
     /// Title row of the table
     title: Option<RowStruct>,
+    /// Row above the title row containing headers of groups of columns
+    groups: Option<RowStruct>,
+    /// Header rows displayed below the title row of the table
+    headers: Vec<RowStruct>,
     /// Rows in the table
     rows: Vec<RowStruct>,
     /// Format of the table
@@ -22,6 +39,14 @@ pub struct TableStruct {
     style: StyleStruct,
     /// Color preferences for printing the table
     color_choice: ColorChoice,
+    /// Selection and arrangement of rows while printing the table
+    view: View,
+    /// Number of rows after which title row is repeated
+    repeat_title: Option<usize>,
+    /// Maximum width of the table
+    max_width: Option<usize>,
+    /// Configuration for splitting a table which is too wide
+    split: Option<Split>,
 }
 
 impl TableStruct {
@@ -31,6 +56,37 @@ impl TableStruct {
         self
     }
 
+    /// Used to add a row above the title row containing headers of groups of columns
+    ///
+    /// Cells of the row can span multiple columns using [`CellStruct::span`]. For example, `vec!["".cell(),
+    /// "Stats".cell().span(2)]` adds a header spanning second and third columns of a table.
+    pub fn column_groups<T: Row>(mut self, groups: T) -> Self {
+        self.groups = Some(groups.row());
+        self
+    }
+
+    /// Used to add a header row below the title row (e.g., units of the values in each column)
+    ///
+    /// Calling this function multiple times adds multiple header rows.
+    pub fn header<T: Row>(mut self, header: T) -> Self {
+        self.headers.push(header.row());
+        self
+    }
+
+    /// Used to add a caption of a table, which is centered across the full width of the table
+    ///
+    /// Last column of the table is widened when the caption is wider than the table.
+    pub fn caption<C: Cell>(mut self, caption: C) -> Self {
+        self.caption = Some(caption.cell().justify(Justify::Center));
+        self
+    }
+
+    /// Used to set the position of caption of a table
+    pub fn caption_position(mut self, caption_position: CaptionPosition) -> Self {
+        self.caption_position = caption_position;
+        self
+    }
+
     /// Used to set border of a table
     pub fn border(mut self, border: Border) -> Self {
         self.format.border = border;
@@ -49,18 +105,255 @@ impl TableStruct {
         self
     }
 
+    /// Used to only print rows for which the predicate returns `true`
+    ///
+    /// Calling this function multiple times prints only the rows which satisfy all the predicates.
+    pub fn filter<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&RowStruct) -> bool + Send + Sync + 'static,
+    {
+        self.view.filters.push(Box::new(predicate));
+        self
+    }
+
+    /// Used to only print first `n` rows of a table
+    ///
+    /// Remaining rows are replaced by a single `… N more rows …` row.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.view.elision = Some(Elision::Head(n));
+        self
+    }
+
+    /// Used to only print last `n` rows of a table
+    ///
+    /// Preceding rows are replaced by a single `… N more rows …` row.
+    pub fn tail(mut self, n: usize) -> Self {
+        self.view.elision = Some(Elision::Tail(n));
+        self
+    }
+
+    /// Used to only print first `n` and last `n` rows of a table
+    ///
+    /// Rows in the middle are replaced by a single `… N more rows …` row.
+    pub fn elide(mut self, n: usize) -> Self {
+        self.view.elision = Some(Elision::Middle(n));
+        self
+    }
+
+    /// Used to group rows of a table by the contents of given column
+    ///
+    /// Each group is preceded by a section header row containing the value of the column for that group. Groups are
+    /// printed in the order of first appearance of their values.
+    ///
+    /// When rows are also elided (e.g., using `elide`), the `… N more rows …` row is placed between groups (after the
+    /// subtotal row of a group, if any) and also counts the groups in which all the rows are hidden.
+    pub fn group_by(mut self, column: usize) -> Self {
+        self.view.grouping = Some(Grouping { column });
+        self
+    }
+
+    /// Used to add a subtotal row at the end of each group containing sums of numeric values in given columns
+    ///
+    /// This has no effect unless rows are grouped using `group_by`.
+    pub fn subtotals<I: IntoIterator<Item = usize>>(mut self, columns: I) -> Self {
+        self.view.subtotals = columns.into_iter().collect();
+        self
+    }
+
+    /// Used to display first column of a table as row headers (stub column)
+    ///
+    /// Cells in the row header column are displayed in bold and the column is separated from other columns using stub
+    /// separator (see [`SeparatorBuilder::stub`]).
+    pub fn row_headers(mut self, row_headers: bool) -> Self {
+        self.view.row_headers = row_headers;
+        self
+    }
+
+    /// Used to set the placeholder displayed in cells for missing values (e.g., `None` fields of a struct deriving
+    /// `Table` or empty CSV fields), which are blank by default
+    ///
+    /// Style of the placeholder is applied on top of the style of each cell, e.g., `"null".cell().dimmed(true)`.
+    pub fn null_placeholder<C: Cell>(mut self, placeholder: C) -> Self {
+        self.view.null_placeholder = Some(placeholder.cell());
+        self
+    }
+
+    /// Used to add an index column containing row numbers at the left of a table
+    ///
+    /// Row numbers start from `start` and are based on the position of rows when the table was created, i.e., they
+    /// remain the same for a row even when other rows are filtered, grouped or elided.
+    pub fn with_index(mut self, start: usize, style: StyleStruct) -> Self {
+        self.view.index = Some(Index { start, style });
+        self
+    }
+
+    /// Used to repeat the title row (along with other header rows) after every `n` rows of a table
+    pub fn repeat_title(mut self, n: usize) -> Self {
+        self.repeat_title = Some(n);
+        self
+    }
+
+    /// Used to apply a style to the cells of a column whose contents satisfy the predicate
+    ///
+    /// Style is applied on top of the existing style of the cells, i.e., only the colors and attributes set in given
+    /// style are changed.
+    pub fn style_if<F>(mut self, column: usize, predicate: F, style: StyleStruct) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.view.rules.push(Rule::Predicate {
+            column,
+            predicate: Box::new(predicate),
+            style,
+        });
+        self
+    }
+
+    /// Used to apply a style to the cells of a column whose contents match the regular expression
+    #[cfg(feature = "regex")]
+    #[cfg_attr(feature = "doc", doc(cfg(feature = "regex")))]
+    pub fn style_match(self, column: usize, regex: regex::Regex, style: StyleStruct) -> Self {
+        self.style_if(column, move |content| regex.is_match(content), style)
+    }
+
+    /// Used to style the cells of a column based on their numeric values
+    ///
+    /// Each cell gets the style of the largest threshold which is not greater than its value, e.g., thresholds
+    /// `[(50.0, yellow), (80.0, red)]` color values from `50` to `80` yellow and values from `80` red. Cells which are
+    /// not numeric are not styled.
+    pub fn threshold<I>(mut self, column: usize, thresholds: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, StyleStruct)>,
+    {
+        let mut thresholds: Vec<(f64, StyleStruct)> = thresholds.into_iter().collect();
+        thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        self.view.rules.push(Rule::Threshold { column, thresholds });
+        self
+    }
+
+    /// Used to color the background of the cells of a column based on their numeric values
+    ///
+    /// Background colors are interpolated between `low` (for minimum value in the column) and `high` (for maximum
+    /// value in the column) RGB colors. Minimum and maximum values are taken from all the rows which satisfy the
+    /// filters of the table, including the rows hidden by elision.
+    pub fn heatmap(mut self, column: usize, low: (u8, u8, u8), high: (u8, u8, u8)) -> Self {
+        self.view.rules.push(Rule::Heatmap { column, low, high });
+        self
+    }
+
+    /// Used to limit the width of the table by shrinking the columns containing lazily rendered cells (see
+    /// [`CellStruct::lazy`]) down to the minimum widths of their cells
+    ///
+    /// Columns which cannot be shrunk are printed with their required widths, so the table may still be wider than
+    /// given width.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Used to split a table wider than `width` into vertical slices which are printed one after another
+    ///
+    /// Each slice repeats the key columns (e.g., a `Name` column identifying the rows) followed by as many of the
+    /// remaining columns as fit in given width. Index column (if present) is always repeated.
+    pub fn split_columns<I: IntoIterator<Item = usize>>(
+        mut self,
+        width: usize,
+        key_columns: I,
+    ) -> Self {
+        self.split = Some(Split {
+            width,
+            key_columns: key_columns.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Removes given columns from the title, headers and rows added to the table so far
+    #[cfg(any(feature = "title", feature = "derive"))]
+    pub(crate) fn skip_columns(mut self, columns: &[usize]) -> Self {
+        if columns.is_empty() {
+            return self;
+        }
+
+        let num_columns = self
+            .title
+            .iter()
+            .chain(self.groups.iter())
+            .chain(self.headers.iter())
+            .chain(self.rows.iter())
+            .map(|row| row.cells.iter().map(CellStruct::columns).sum::<usize>())
+            .max()
+            .unwrap_or_default();
+
+        let kept: Vec<usize> = (0..num_columns)
+            .filter(|column| !columns.contains(column))
+            .collect();
+
+        self.title = self.title.map(|title| title.project(&kept));
+        self.groups = self.groups.map(|groups| groups.project(&kept));
+
+        for row in self.headers.iter_mut().chain(self.rows.iter_mut()) {
+            *row = row.project(&kept);
+        }
+
+        self
+    }
+
     /// Returns a struct which implements the `Display` trait
     pub fn display(&self) -> Result<TableDisplay> {
         let writer = BufferWriter::stdout(self.color_choice);
         let buffers = self.buffers(&writer)?;
 
-        let mut output = Vec::new();
+        Ok(display(buffers))
+    }
 
-        for buffer in buffers {
-            output.append(&mut buffer.into_inner());
-        }
+    /// Splits the table into pages of given height (in lines) and returns a struct implementing the `Display` trait
+    /// for each page
+    ///
+    /// Each page contains its own borders, caption and header rows, followed by a `Page x of y` footer line. Rows are
+    /// never split across pages, so a page may be taller than given height if a single row does not fit in it.
+    pub fn pages(&self, height: usize) -> Result<Vec<TableDisplay>> {
+        let writer = BufferWriter::stdout(self.color_choice);
 
-        Ok(TableDisplay::new(output))
+        let headers = self
+            .view
+            .headers(self.groups.as_ref(), self.title.as_ref(), &self.headers);
+        let body = self.body();
+        let format = self.format();
+        let table_dimension = self.fitted_dimension(&headers, &body, &format);
+
+        let (header_heights, body_heights) = table_dimension.heights.split_at(headers.len());
+
+        let ranges = self.page_ranges(&format, &headers, &body, &table_dimension, height);
+        let num_pages = ranges.len();
+
+        ranges
+            .into_iter()
+            .enumerate()
+            .map(|(page, range)| {
+                let page_dimension = Dimension {
+                    widths: table_dimension.widths.clone(),
+                    heights: header_heights
+                        .iter()
+                        .chain(&body_heights[range.clone()])
+                        .copied()
+                        .collect(),
+                };
+
+                let footer = format!("Page {} of {}", page + 1, num_pages).cell();
+
+                let buffers = self.page_buffers(
+                    &format,
+                    &writer,
+                    &headers,
+                    &body[range],
+                    &page_dimension,
+                    Some(&footer),
+                )?;
+
+                Ok(display(buffers))
+            })
+            .collect()
     }
 
     /// Prints current table to `stdout`
@@ -68,6 +361,33 @@ impl TableStruct {
         self.print_writer(BufferWriter::stdout(self.color_choice))
     }
 
+    /// Prints current table to `stdout` through a pager when it is taller than the terminal
+    pub(crate) fn print_paged(&self) -> Result<()> {
+        if !std::io::stdout().is_terminal() {
+            return self.print_stdout();
+        }
+
+        self.print_pager(&pager(), terminal_height())
+    }
+
+    /// Prints current table through given pager command if it is taller than given height
+    pub(crate) fn print_pager(&self, pager: &str, height: Option<usize>) -> Result<()> {
+        let writer = BufferWriter::stdout(self.color_choice);
+        let buffers = self.buffers(&writer)?;
+
+        let output = bytes(&buffers);
+
+        let lines = output.iter().filter(|byte| **byte == b'\n').count();
+
+        if height.is_some_and(|height| lines < height) || !page(&output, pager)? {
+            for buffer in buffers.iter() {
+                writer.print(buffer)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Prints current table to `stderr`
     pub(crate) fn print_stderr(&self) -> Result<()> {
         self.print_writer(BufferWriter::stderr(self.color_choice))
@@ -77,106 +397,699 @@ impl TableStruct {
         self.style.color_spec()
     }
 
-    fn required_dimension(&self) -> Dimension {
-        let mut heights = Vec::with_capacity(self.rows.len() + 1);
-        let mut widths = Vec::new();
+    /// Returns the format used for printing the table
+    fn format(&self) -> TableFormat {
+        let stub = match self.view.index {
+            Some(_) => 1,
+            None => 0,
+        };
 
-        let title_dimension = self.title.as_ref().map(RowStruct::required_dimension);
+        TableFormat {
+            stub: self.view.row_headers.then_some(stub),
+            ..self.format
+        }
+    }
 
-        if let Some(title_dimension) = title_dimension {
-            widths = title_dimension.widths;
-            heights.push(title_dimension.height);
+    /// Returns the entries in the body of the table
+    fn body(&self) -> Vec<BodyRow<'_>> {
+        let body = self.view.body(&self.rows);
+
+        let every = match self.repeat_title {
+            Some(every)
+                if every > 0
+                    && (self.groups.is_some()
+                        || self.title.is_some()
+                        || !self.headers.is_empty()) =>
+            {
+                every
+            }
+            _ => return body,
+        };
+
+        let mut repeated = Vec::with_capacity(body.len() + body.len() / every);
+        let mut rows = 0;
+
+        for body_row in body {
+            if let BodyRow::Row(..) = body_row {
+                if rows > 0 && rows % every == 0 {
+                    repeated.push(BodyRow::Headers);
+                }
+
+                rows += 1;
+            }
+
+            repeated.push(body_row);
         }
 
-        for row in self.rows.iter() {
-            let row_dimension = row.required_dimension();
+        repeated
+    }
+
+    fn required_dimension(
+        &self,
+        headers: &[Cow<'_, RowStruct>],
+        body: &[BodyRow<'_>],
+    ) -> Dimension {
+        let mut heights = Vec::with_capacity(headers.len() + body.len());
+        let mut widths = Vec::new();
+
+        for header in headers.iter() {
+            let row_dimension = header.required_dimension();
 
             heights.push(row_dimension.height);
+            merge_widths(&mut widths, row_dimension.widths);
+        }
 
-            let new_widths = row_dimension.widths;
+        for body_row in body.iter() {
+            match body_row {
+                BodyRow::Row(_, row) => {
+                    let row_dimension = row.required_dimension();
 
-            if widths.is_empty() {
-                widths = new_widths;
-            } else {
-                for (width, new_width) in widths.iter_mut().zip(new_widths.into_iter()) {
-                    *width = std::cmp::max(new_width, *width);
+                    heights.push(row_dimension.height);
+                    merge_widths(&mut widths, row_dimension.widths);
+                }
+                BodyRow::Span(cell) | BodyRow::Section(cell) => {
+                    heights.push(cell.required_dimension().height)
                 }
+                BodyRow::Headers => heights.push(0),
+            }
+        }
+
+        let format = self.format();
+        let span_width = self.required_span_width(body, &format);
+
+        let mut dimension = Dimension { widths, heights };
+        dimension.fit_span(span_width, &format);
+
+        for header in headers.iter() {
+            header.fit_spans(&mut dimension.widths, &format);
+        }
+
+        dimension
+    }
+
+    /// Returns the dimension of the table after shrinking its columns to fit in maximum width of the table (if any)
+    fn fitted_dimension(
+        &self,
+        headers: &[Cow<'_, RowStruct>],
+        body: &[BodyRow<'_>],
+        format: &TableFormat,
+    ) -> Dimension {
+        let mut dimension = self.required_dimension(headers, body);
+
+        let Some(max_width) = self.max_width else {
+            return dimension;
+        };
+
+        let mut min_widths = vec![0; dimension.widths.len()];
+
+        let rows = headers
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(body.iter().filter_map(|body_row| match body_row {
+                BodyRow::Row(_, row) => Some(row.as_ref()),
+                _ => None,
+            }));
+
+        // Spanning cells are fitted after shrinking the columns
+        for row in rows.filter(|row| !row.is_spanned()) {
+            for (min_width, cell) in min_widths.iter_mut().zip(row.cells.iter()) {
+                *min_width = std::cmp::max(cell.min_width_required(), *min_width);
             }
         }
 
-        Dimension { widths, heights }
+        for _ in max_width..dimension.width(format) {
+            // Shrink the column with most room for shrinking
+            let column = (0..min_widths.len())
+                .filter(|column| dimension.widths[*column] > min_widths[*column])
+                .max_by_key(|column| dimension.widths[*column] - min_widths[*column]);
+
+            match column {
+                Some(column) => dimension.widths[column] -= 1,
+                None => break,
+            }
+        }
+
+        dimension.fit_span(self.required_span_width(body, format), format);
+
+        for header in headers.iter() {
+            header.fit_spans(&mut dimension.widths, format);
+        }
+
+        dimension
+    }
+
+    /// Returns the width required by cells spanning all the columns of the table
+    ///
+    /// Caption above the table spans its borders too, so that it is never wider than the table.
+    fn required_span_width(&self, body: &[BodyRow<'_>], format: &TableFormat) -> usize {
+        let borders = [format.border.left, format.border.right]
+            .iter()
+            .filter(|border| border.is_some())
+            .count();
+
+        let caption_above = self
+            .caption_above()
+            .map(|caption| caption.required_dimension().width.saturating_sub(borders));
+
+        body.iter()
+            .filter_map(BodyRow::span)
+            .chain(self.caption_inside())
+            .map(|cell| cell.required_dimension().width)
+            .chain(caption_above)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn caption_inside(&self) -> Option<&CellStruct> {
+        self.caption
+            .as_ref()
+            .filter(|_| self.caption_position == CaptionPosition::Inside)
+    }
+
+    fn caption_above(&self) -> Option<&CellStruct> {
+        self.caption
+            .as_ref()
+            .filter(|_| self.caption_position == CaptionPosition::Above)
     }
 
     fn buffers(&self, writer: &BufferWriter) -> Result<Vec<Buffer>> {
-        let table_dimension = self.required_dimension();
-        let row_dimensions: Vec<RowDimension> = table_dimension.clone().into();
-        let mut row_dimensions = row_dimensions.into_iter();
+        self.fitted_buffers(writer, &[])
+    }
+
+    /// Returns the widths of columns required for printing the table
+    pub(crate) fn required_widths(&self) -> Vec<usize> {
+        let headers = self
+            .view
+            .headers(self.groups.as_ref(), self.title.as_ref(), &self.headers);
+        let body = self.body();
+
+        self.required_dimension(&headers, &body).widths
+    }
+
+    /// Returns the buffers for the table after widening its columns to at least given widths
+    pub(crate) fn fitted_buffers(
+        &self,
+        writer: &BufferWriter,
+        widths: &[usize],
+    ) -> Result<Vec<Buffer>> {
+        self.formatted_buffers(writer, widths, self.format())
+    }
+
+    /// Returns the lines of the table when printed inside a cell
+    ///
+    /// Last column of the table is widened by `extra_width` (unless the table is split into vertical slices, which may
+    /// be narrower than the cell and are padded instead).
+    fn nested_buffers(
+        &self,
+        writer: &BufferWriter,
+        extra_width: usize,
+        merge_border: bool,
+    ) -> Result<Vec<Buffer>> {
+        let mut widths = Vec::new();
+
+        if extra_width > 0 && self.split.is_none() {
+            widths = self.required_widths();
+
+            if let Some(last_width) = widths.last_mut() {
+                *last_width += extra_width;
+            }
+        }
+
+        let output =
+            bytes(&self.formatted_buffers(writer, &widths, self.nested_format(merge_border))?);
+
+        nested_lines(&output)
+            .map(|line| {
+                let mut buffer = writer.buffer();
+                buffer.write_all(line)?;
+                Ok(buffer)
+            })
+            .collect()
+    }
+
+    /// Returns the format used for printing the table inside a cell
+    fn nested_format(&self, merge_border: bool) -> TableFormat {
+        let mut format = self.format();
+
+        if merge_border {
+            format.border = Border::builder().build();
+        }
+
+        format
+    }
+
+    /// Returns the buffers for the table printed using given format after widening its columns to at least given
+    /// widths
+    fn formatted_buffers(
+        &self,
+        writer: &BufferWriter,
+        widths: &[usize],
+        format: TableFormat,
+    ) -> Result<Vec<Buffer>> {
+        let headers = self
+            .view
+            .headers(self.groups.as_ref(), self.title.as_ref(), &self.headers);
+        let body = self.body();
+        let mut table_dimension = self.fitted_dimension(&headers, &body, &format);
+
+        for (width, min_width) in table_dimension.widths.iter_mut().zip(widths) {
+            *width = std::cmp::max(*min_width, *width);
+        }
+
+        let slices = match self.split {
+            Some(ref split) if table_dimension.width(&format) > split.width => {
+                self.column_slices(split, &table_dimension, &format)
+            }
+            _ => {
+                return self.page_buffers(&format, writer, &headers, &body, &table_dimension, None);
+            }
+        };
+
+        let mut buffers = Buffers::new(writer);
+
+        for (i, columns) in slices.iter().enumerate() {
+            if i > 0 {
+                println(&mut buffers)?;
+            }
+
+            let slice_headers: Vec<Cow<'_, RowStruct>> = headers
+                .iter()
+                .map(|header| Cow::Owned(header.project(columns)))
+                .collect();
+
+            let slice_body: Vec<BodyRow<'_>> = body
+                .iter()
+                .map(|body_row| match body_row {
+                    BodyRow::Row(position, row) => {
+                        BodyRow::Row(*position, Cow::Owned(row.project(columns)))
+                    }
+                    BodyRow::Span(cell) => BodyRow::Span(cell.clone()),
+                    BodyRow::Section(cell) => BodyRow::Section(cell.clone()),
+                    BodyRow::Headers => BodyRow::Headers,
+                })
+                .collect();
+
+            let slice_format = format.project(columns);
+
+            let mut slice_dimension = Dimension {
+                widths: columns
+                    .iter()
+                    .map(|column| table_dimension.widths[*column])
+                    .collect(),
+                heights: table_dimension.heights.clone(),
+            };
+            slice_dimension.fit_span(
+                self.required_span_width(&slice_body, &slice_format),
+                &slice_format,
+            );
+
+            let mut slice_buffers = self.page_buffers(
+                &slice_format,
+                writer,
+                &slice_headers,
+                &slice_body,
+                &slice_dimension,
+                None,
+            )?;
+
+            buffers.append(&mut slice_buffers)?;
+        }
+
+        buffers.into_vec()
+    }
+
+    /// Returns the columns to be printed in each vertical slice of a table which is wider than allowed width
+    ///
+    /// Each slice starts with index column (if present) and key columns, followed by as many of the remaining columns
+    /// as fit in the allowed width (at least one per slice).
+    fn column_slices(
+        &self,
+        split: &Split,
+        table_dimension: &Dimension,
+        format: &TableFormat,
+    ) -> Vec<Vec<usize>> {
+        let num_columns = table_dimension.widths.len();
+        let offset = usize::from(self.view.index.is_some());
+
+        let mut keys: Vec<usize> = (0..offset).collect();
+
+        for column in split.key_columns.iter().map(|column| column + offset) {
+            if column < num_columns && !keys.contains(&column) {
+                keys.push(column);
+            }
+        }
+
+        let width = |columns: &[usize]| {
+            Dimension {
+                widths: columns
+                    .iter()
+                    .map(|column| table_dimension.widths[*column])
+                    .collect(),
+                heights: Vec::new(),
+            }
+            .width(&format.project(columns))
+        };
+
+        let mut slices = Vec::new();
+        let mut current = keys.clone();
+
+        for column in (0..num_columns).filter(|column| !keys.contains(column)) {
+            current.push(column);
+
+            if current.len() > keys.len() + 1 && width(&current) > split.width {
+                current.pop();
+                slices.push(std::mem::replace(&mut current, keys.clone()));
+                current.push(column);
+            }
+        }
+
+        if current.len() > keys.len() || slices.is_empty() {
+            slices.push(current);
+        }
+
+        slices
+    }
+
+    /// Returns the buffers for a table containing given header and body rows
+    ///
+    /// Heights in `table_dimension` should contain heights of header rows followed by heights of body rows.
+    fn page_buffers(
+        &self,
+        format: &TableFormat,
+        writer: &BufferWriter,
+        headers: &[Cow<'_, RowStruct>],
+        body: &[BodyRow<'_>],
+        table_dimension: &Dimension,
+        footer: Option<&CellStruct>,
+    ) -> Result<Vec<Buffer>> {
+        let span_width = table_dimension.span_width(format);
         let color_spec = self.color_spec();
 
+        let (header_heights, body_heights) = table_dimension.heights.split_at(headers.len());
+
+        let first_is_row = !headers.is_empty()
+            || body
+                .first()
+                .is_some_and(|body_row| body_row.span().is_none());
+
         let mut buffers = Buffers::new(writer);
 
-        print_horizontal_line(
+        if let Some(caption) = self.caption_above() {
+            let mut caption_buffers =
+                self.outside_buffers(format, writer, caption, table_dimension, &color_spec)?;
+
+            buffers.append(&mut caption_buffers)?;
+        }
+
+        // Line above the first header row has junctions only between the cells of that row
+        let first_header: Vec<&RowStruct> =
+            headers.first().map(AsRef::as_ref).into_iter().collect();
+
+        print_line_between(
             &mut buffers,
-            self.format.border.top.as_ref(),
-            &table_dimension,
-            &self.format,
+            with_junctions(
+                format.border.top.as_ref(),
+                self.caption_inside().is_none() && first_is_row,
+            )
+            .as_ref(),
+            table_dimension,
+            format,
             &color_spec,
+            &first_header,
         )?;
 
-        if let Some(ref title) = self.title {
-            let title_dimension = row_dimensions.next().unwrap();
-            let mut title_buffers =
-                title.buffers(writer, title_dimension, &self.format, &color_spec)?;
+        if let Some(caption) = self.caption_inside() {
+            let cell_dimension = CellDimension {
+                width: span_width,
+                height: caption.required_dimension().height,
+            };
 
-            buffers.append(&mut title_buffers)?;
+            let mut caption_buffers =
+                span_buffers(caption, writer, cell_dimension, format, &color_spec)?;
 
-            if self.format.separator.title.is_some() {
-                print_horizontal_line(
-                    &mut buffers,
-                    self.format.separator.title.as_ref(),
-                    &table_dimension,
-                    &self.format,
-                    &color_spec,
-                )?
+            buffers.append(&mut caption_buffers)?;
+
+            let line = if headers.is_empty() && body.is_empty() {
+                format.border.bottom.as_ref()
             } else {
-                print_horizontal_line(
-                    &mut buffers,
-                    self.format.separator.row.as_ref(),
-                    &table_dimension,
-                    &self.format,
-                    &color_spec,
-                )?
-            }
+                format.title_separator()
+            };
+
+            print_line_between(
+                &mut buffers,
+                with_junctions(line, first_is_row).as_ref(),
+                table_dimension,
+                format,
+                &color_spec,
+                &first_header,
+            )?;
         }
 
-        let mut rows = self.rows.iter().zip(row_dimensions).peekable();
+        if !headers.is_empty() {
+            let mut header_buffers = self.header_buffers(
+                format,
+                writer,
+                headers,
+                header_heights,
+                table_dimension,
+                &color_spec,
+            )?;
+
+            buffers.append(&mut header_buffers)?;
+
+            let (line, rows) = if body.is_empty() {
+                let last_header = headers.last().map(AsRef::as_ref);
+                (
+                    format.border.bottom.as_ref(),
+                    last_header.into_iter().collect(),
+                )
+            } else {
+                (format.title_separator(), Vec::new())
+            };
+
+            print_line_between(
+                &mut buffers,
+                line,
+                table_dimension,
+                format,
+                &color_spec,
+                &rows,
+            )?;
+        }
 
-        while let Some((row, row_dimension)) = rows.next() {
-            let mut row_buffers = row.buffers(writer, row_dimension, &self.format, &color_spec)?;
+        let mut rows = body.iter().zip(body_heights).peekable();
 
-            buffers.append(&mut row_buffers)?;
+        while let Some((body_row, height)) = rows.next() {
+            let mut row_buffers = match body_row {
+                BodyRow::Row(_, row) => {
+                    let row_dimension = RowDimension {
+                        widths: table_dimension.widths.clone(),
+                        height: *height,
+                    };
 
-            match rows.peek() {
-                Some(_) => print_horizontal_line(
-                    &mut buffers,
-                    self.format.separator.row.as_ref(),
-                    &table_dimension,
-                    &self.format,
+                    row.buffers(writer, row_dimension, format, &color_spec)?
+                }
+                BodyRow::Span(cell) | BodyRow::Section(cell) => {
+                    let cell_dimension = CellDimension {
+                        width: span_width,
+                        height: *height,
+                    };
+
+                    span_buffers(cell, writer, cell_dimension, format, &color_spec)?
+                }
+                BodyRow::Headers => self.header_buffers(
+                    format,
+                    writer,
+                    headers,
+                    header_heights,
+                    table_dimension,
                     &color_spec,
                 )?,
-                None => print_horizontal_line(
+            };
+
+            buffers.append(&mut row_buffers)?;
+
+            let next_row = rows.peek().map(|(next_row, _)| *next_row);
+
+            let junctions = body_row.span().is_none()
+                || next_row.is_some_and(|next_row| next_row.span().is_none());
+
+            print_horizontal_line(
+                &mut buffers,
+                with_junctions(format.line_below(body_row, next_row), junctions).as_ref(),
+                table_dimension,
+                format,
+                &color_spec,
+            )?;
+        }
+
+        if let Some(footer) = footer {
+            let mut footer_buffers =
+                self.outside_buffers(format, writer, footer, table_dimension, &color_spec)?;
+
+            buffers.append(&mut footer_buffers)?;
+        }
+
+        buffers.into_vec()
+    }
+
+    /// Returns the buffers for header rows separated by row separators
+    fn header_buffers(
+        &self,
+        format: &TableFormat,
+        writer: &BufferWriter,
+        headers: &[Cow<'_, RowStruct>],
+        heights: &[usize],
+        table_dimension: &Dimension,
+        color_spec: &ColorSpec,
+    ) -> Result<Vec<Buffer>> {
+        let mut buffers = Buffers::new(writer);
+
+        let mut header_rows = headers.iter().zip(heights).peekable();
+
+        while let Some((header, height)) = header_rows.next() {
+            let header_dimension = RowDimension {
+                widths: table_dimension.widths.clone(),
+                height: *height,
+            };
+
+            let mut header_buffers =
+                header.buffers(writer, header_dimension, format, color_spec)?;
+
+            buffers.append(&mut header_buffers)?;
+
+            if let Some((next_header, _)) = header_rows.peek() {
+                print_line_between(
                     &mut buffers,
-                    self.format.border.bottom.as_ref(),
-                    &table_dimension,
-                    &self.format,
-                    &color_spec,
-                )?,
+                    format.separator.row.as_ref(),
+                    table_dimension,
+                    format,
+                    color_spec,
+                    &[header.as_ref(), next_header.as_ref()],
+                )?;
             }
         }
 
         buffers.into_vec()
     }
 
+    /// Returns the buffers for a cell centered across the full width of the table, displayed outside its borders
+    fn outside_buffers(
+        &self,
+        format: &TableFormat,
+        writer: &BufferWriter,
+        cell: &CellStruct,
+        table_dimension: &Dimension,
+        color_spec: &ColorSpec,
+    ) -> Result<Vec<Buffer>> {
+        let borders = [format.border.left, format.border.right]
+            .iter()
+            .filter(|border| border.is_some())
+            .count();
+
+        let required_dimension = cell.required_dimension();
+        let cell_dimension = CellDimension {
+            width: std::cmp::max(
+                table_dimension.span_width(format) + borders,
+                required_dimension.width,
+            ),
+            height: required_dimension.height,
+        };
+
+        let format = TableFormat {
+            border: Border {
+                left: None,
+                right: None,
+                ..format.border
+            },
+            ..*format
+        };
+
+        span_buffers(cell, writer, cell_dimension, &format, color_spec)
+    }
+
+    /// Returns the ranges of body rows to be printed on each page when pages have given height
+    fn page_ranges(
+        &self,
+        format: &TableFormat,
+        headers: &[Cow<'_, RowStruct>],
+        body: &[BodyRow<'_>],
+        table_dimension: &Dimension,
+        height: usize,
+    ) -> Vec<Range<usize>> {
+        let (header_heights, body_heights) = table_dimension.heights.split_at(headers.len());
+
+        let line = |line: Option<&HorizontalLine>| usize::from(line.is_some());
+
+        let header_lines = header_heights.iter().sum::<usize>()
+            + line(format.separator.row.as_ref()) * headers.len().saturating_sub(1);
+
+        let mut overhead = line(format.border.top.as_ref())
+            + header_lines
+            + line(format.border.bottom.as_ref())
+            + 1;
+
+        if !headers.is_empty() {
+            overhead += line(format.title_separator());
+        }
+
+        if let Some(caption) = self.caption.as_ref() {
+            overhead += caption.required_dimension().height;
+
+            if self.caption_position == CaptionPosition::Inside {
+                overhead += line(format.title_separator());
+            }
+        }
+
+        let lines = |index: usize| match body[index] {
+            BodyRow::Headers => header_lines,
+            _ => body_heights[index],
+        };
+
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        let mut page_lines = overhead;
+
+        for index in 0..body.len() {
+            if index == start {
+                if let BodyRow::Headers = body[index] {
+                    start += 1;
+                } else {
+                    page_lines += lines(index);
+                }
+
+                continue;
+            }
+
+            let separator = line(format.line_below(&body[index - 1], Some(&body[index])));
+
+            if page_lines + separator + lines(index) > height {
+                let mut end = index;
+
+                while let BodyRow::Headers = body[end - 1] {
+                    end -= 1;
+                }
+
+                ranges.push(start..end);
+
+                start = index;
+                page_lines = overhead;
+
+                if let BodyRow::Headers = body[index] {
+                    start += 1;
+                } else {
+                    page_lines += lines(index);
+                }
+            } else {
+                page_lines += separator + lines(index);
+            }
+        }
+
+        ranges.push(start..std::cmp::max(start, body.len()));
+
+        ranges
+    }
+
     fn print_writer(&self, writer: BufferWriter) -> Result<()> {
         let buffers = self.buffers(&writer)?;
 
@@ -188,6 +1101,147 @@ impl TableStruct {
     }
 }
 
+/// Merges widths of a row into widths of a table
+fn merge_widths(widths: &mut Vec<usize>, new_widths: Vec<usize>) {
+    if widths.is_empty() {
+        *widths = new_widths;
+    } else {
+        for (width, new_width) in widths.iter_mut().zip(new_widths) {
+            *width = std::cmp::max(new_width, *width);
+        }
+    }
+}
+
+/// Converts buffers into a struct which implements the `Display` trait
+fn display(buffers: Vec<Buffer>) -> TableDisplay {
+    let mut output = Vec::new();
+
+    for buffer in buffers {
+        output.append(&mut buffer.into_inner());
+    }
+
+    TableDisplay::new(output)
+}
+
+/// Returns the contents of given buffers concatenated together
+fn bytes(buffers: &[Buffer]) -> Vec<u8> {
+    buffers
+        .iter()
+        .flat_map(|buffer| buffer.as_slice())
+        .copied()
+        .collect()
+}
+
+/// Table nested inside a cell
+///
+/// Dimension of the table is computed once for each format (i.e., with its own border or with its border merged into
+/// the cell) from its rendered lines as it requires rendering the whole table.
+pub(crate) struct NestedTable {
+    table: TableStruct,
+    /// Dimensions of the table with its own border and with its border merged into the cell
+    dimensions: [OnceLock<CellDimension>; 2],
+}
+
+impl NestedTable {
+    /// Returns the dimension of the table when printed inside a cell
+    pub(crate) fn dimension(&self, merge_border: bool) -> CellDimension {
+        *self.dimensions[usize::from(merge_border)].get_or_init(|| {
+            let writer = BufferWriter::stdout(ColorChoice::Never);
+
+            self.table
+                .formatted_buffers(&writer, &[], self.table.nested_format(merge_border))
+                .map(|buffers| nested_dimension(&buffers))
+                .unwrap_or(CellDimension {
+                    width: 0,
+                    height: 0,
+                })
+        })
+    }
+
+    /// Returns the buffers for each line of the table when printed inside a cell of given width
+    ///
+    /// Lines narrower than given width are padded with spaces.
+    pub(crate) fn buffers(
+        &self,
+        writer: &BufferWriter,
+        width: usize,
+        merge_border: bool,
+    ) -> Result<Vec<Buffer>> {
+        let extra_width = width.saturating_sub(self.dimension(merge_border).width);
+        let mut lines = self
+            .table
+            .nested_buffers(writer, extra_width, merge_border)?;
+
+        for line in lines.iter_mut() {
+            let line_width = display_width(&String::from_utf8_lossy(line.as_slice()));
+            write!(line, "{}", " ".repeat(width.saturating_sub(line_width)))?;
+        }
+
+        Ok(lines)
+    }
+}
+
+impl Cell for TableStruct {
+    /// Converts a table into a cell which can be nested in another table
+    ///
+    /// Nested table is printed with the color preferences of parent table and is widened to fill its column.
+    fn cell(self) -> CellStruct {
+        let writer = BufferWriter::stdout(ColorChoice::Never);
+        let buffers = self.buffers(&writer).unwrap_or_default();
+
+        // Table is rendered with its own border here, so its dimension in that format is already known
+        let dimensions = [OnceLock::from(nested_dimension(&buffers)), OnceLock::new()];
+
+        let data = display(buffers)
+            .to_string()
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(ToString::to_string)
+            .collect();
+
+        CellStruct {
+            data,
+            format: Default::default(),
+            style: Default::default(),
+            table: Some(Arc::new(NestedTable {
+                table: self,
+                dimensions,
+            })),
+            lazy: None,
+            null: false,
+        }
+    }
+}
+
+/// Returns the lines of a table printed inside a cell
+///
+/// Empty lines (e.g., between vertical slices of a table split using `split_columns`) are dropped.
+fn nested_lines(output: &[u8]) -> impl Iterator<Item = &[u8]> {
+    output
+        .split(|byte| *byte == b'\n')
+        .filter(|line| !line.is_empty())
+}
+
+/// Returns the dimension of a table printed inside a cell from its rendered lines
+fn nested_dimension(buffers: &[Buffer]) -> CellDimension {
+    let output = bytes(buffers);
+
+    let mut dimension = CellDimension {
+        width: 0,
+        height: 0,
+    };
+
+    for line in nested_lines(&output) {
+        dimension.width = std::cmp::max(
+            dimension.width,
+            display_width(&String::from_utf8_lossy(line)),
+        );
+        dimension.height += 1;
+    }
+
+    dimension
+}
+
 /// Trait to convert raw type into table
 pub trait Table {
     /// Converts raw type to a table
@@ -203,11 +1257,19 @@ where
         let rows = self.into_iter().map(Row::row).collect();
 
         TableStruct {
+            caption: None,
+            caption_position: Default::default(),
             title: Default::default(),
+            groups: None,
+            headers: Vec::new(),
             rows,
             format: Default::default(),
             style: Default::default(),
             color_choice: ColorChoice::Always,
+            view: Default::default(),
+            repeat_title: None,
+            max_width: None,
+            split: None,
         }
     }
 }
@@ -281,6 +1343,7 @@ pub struct HorizontalLine {
     pub(crate) right_end: char,
     pub(crate) junction: char,
     pub(crate) filler: char,
+    pub(crate) stub_junction: Option<char>,
 }
 
 impl Default for HorizontalLine {
@@ -290,6 +1353,7 @@ impl Default for HorizontalLine {
             right_end: '+',
             junction: '+',
             filler: '-',
+            stub_junction: None,
         }
     }
 }
@@ -302,6 +1366,22 @@ impl HorizontalLine {
             right_end,
             junction,
             filler,
+            stub_junction: None,
+        }
+    }
+
+    /// Sets the junction displayed where the line crosses stub column separator (by default, same as `junction`)
+    pub fn stub_junction(mut self, stub_junction: char) -> Self {
+        self.stub_junction = Some(stub_junction);
+        self
+    }
+
+    /// Returns a copy of horizontal line with junctions replaced by filler (used next to cells spanning all columns)
+    pub(crate) fn without_junctions(&self) -> Self {
+        Self {
+            junction: self.filler,
+            stub_junction: None,
+            ..*self
         }
     }
 }
@@ -379,6 +1459,8 @@ pub struct Separator {
     pub(crate) column: Option<VerticalLine>,
     pub(crate) row: Option<HorizontalLine>,
     pub(crate) title: Option<HorizontalLine>,
+    pub(crate) group: Option<HorizontalLine>,
+    pub(crate) stub: Option<VerticalLine>,
 }
 
 impl Separator {
@@ -388,6 +1470,8 @@ impl Separator {
             column: None,
             row: None,
             title: None,
+            group: None,
+            stub: None,
         })
     }
 }
@@ -398,6 +1482,8 @@ impl Default for Separator {
             column: Some(Default::default()),
             row: Some(Default::default()),
             title: None,
+            group: None,
+            stub: None,
         }
     }
 }
@@ -429,17 +1515,120 @@ impl SeparatorBuilder {
         self
     }
 
+    /// Set separator between groups of rows of a table
+    ///
+    /// # None
+    ///
+    /// When group separator is not preset (i.e., it is `None`), row separator is displayed in place of group separator.
+    pub fn group(mut self, group: Option<HorizontalLine>) -> Self {
+        self.0.group = group;
+        self
+    }
+
+    /// Set separator between row header column (stub column) and other columns of a table
+    ///
+    /// # None
+    ///
+    /// When stub separator is not preset (i.e., it is `None`), column separator is displayed in place of stub
+    /// separator.
+    pub fn stub(mut self, stub: Option<VerticalLine>) -> Self {
+        self.0.stub = stub;
+        self
+    }
+
     /// Build separator
     pub fn build(self) -> Separator {
         self.0
     }
 }
 
+/// Configuration for splitting a table which is wider than allowed width
+#[derive(Debug, Clone)]
+struct Split {
+    /// Maximum width of each slice of the table
+    width: usize,
+    /// Columns to be repeated in each slice of the table
+    key_columns: Vec<usize>,
+}
+
+/// Position of the caption of a table
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CaptionPosition {
+    /// Displays caption inside the top border of a table, separated from rest of the table by title separator
+    #[default]
+    Inside,
+    /// Displays caption above the top border of a table
+    Above,
+}
+
 /// Struct for configuring a table's format
 #[derive(Debug, Default, Copy, Clone)]
 pub(crate) struct TableFormat {
     pub(crate) border: Border,
     pub(crate) separator: Separator,
+    /// Index of the column after which stub separator is displayed
+    pub(crate) stub: Option<usize>,
+}
+
+impl TableFormat {
+    /// Returns the separator displayed after given column
+    pub(crate) fn column_separator(&self, column: usize) -> Option<&VerticalLine> {
+        if self.stub == Some(column) {
+            self.separator
+                .stub
+                .as_ref()
+                .or(self.separator.column.as_ref())
+        } else {
+            self.separator.column.as_ref()
+        }
+    }
+
+    /// Returns the format for printing given columns of a table
+    pub(crate) fn project(&self, columns: &[usize]) -> Self {
+        let stub = self
+            .stub
+            .and_then(|stub| columns.iter().position(|column| *column == stub))
+            .filter(|position| position + 1 < columns.len());
+
+        Self { stub, ..*self }
+    }
+
+    /// Returns the horizontal line displayed below an entry in the body of a table
+    pub(crate) fn line_below(
+        &self,
+        body_row: &BodyRow<'_>,
+        next_row: Option<&BodyRow<'_>>,
+    ) -> Option<&HorizontalLine> {
+        match (body_row, next_row) {
+            (_, None) => self.border.bottom.as_ref(),
+            (BodyRow::Headers, Some(_)) => self.title_separator(),
+            (_, Some(BodyRow::Section(_))) => self
+                .separator
+                .group
+                .as_ref()
+                .or(self.separator.row.as_ref()),
+            (_, Some(_)) => self.separator.row.as_ref(),
+        }
+    }
+
+    /// Returns the junction of a horizontal line displayed after given column
+    pub(crate) fn junction(&self, line: &HorizontalLine, column: usize) -> Option<char> {
+        self.column_separator(column)?;
+
+        if self.stub == Some(column) {
+            Some(line.stub_junction.unwrap_or(line.junction))
+        } else {
+            Some(line.junction)
+        }
+    }
+
+    /// Returns the separator displayed below title, i.e., title separator if present or row separator otherwise
+    pub(crate) fn title_separator(&self) -> Option<&HorizontalLine> {
+        self.separator
+            .title
+            .as_ref()
+            .or(self.separator.row.as_ref())
+    }
 }
 
 /// Dimensions of a table
@@ -451,6 +1640,43 @@ pub(crate) struct Dimension {
     pub(crate) heights: Vec<usize>,
 }
 
+impl Dimension {
+    /// Returns the width available for a cell spanning all the columns of a table
+    pub(crate) fn span_width(&self, format: &TableFormat) -> usize {
+        if self.widths.is_empty() {
+            return 0;
+        }
+
+        let separators = (0..(self.widths.len() - 1))
+            .filter(|column| format.column_separator(*column).is_some())
+            .count();
+
+        self.widths.iter().sum::<usize>() + (2 * self.widths.len()) + separators - 2
+    }
+
+    /// Returns the total width of a table (including borders)
+    pub(crate) fn width(&self, format: &TableFormat) -> usize {
+        let borders = [format.border.left, format.border.right]
+            .iter()
+            .filter(|border| border.is_some())
+            .count();
+
+        self.span_width(format) + 2 + borders
+    }
+
+    /// Widens last column of a table (if required) so that a cell of given width can span all the columns
+    pub(crate) fn fit_span(&mut self, span_width: usize, format: &TableFormat) {
+        let available_span_width = self.span_width(format);
+
+        if span_width > available_span_width {
+            match self.widths.last_mut() {
+                Some(width) => *width += span_width - available_span_width,
+                None => self.widths.push(span_width),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -462,4 +1688,437 @@ mod tests {
         assert_eq!(2, table.rows[0].cells.len());
         assert_eq!(2, table.rows[1].cells.len());
     }
+
+    #[test]
+    fn test_elide_rows() {
+        let table = (1..=6)
+            .map(|i| vec![i * 100])
+            .table()
+            .filter(|row| row.cells()[0].content() != "300")
+            .elide(1)
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++-----------------+
+| 100             |
++-----------------+
+| … 3 more rows … |
++-----------------+
+| 600             |
++-----------------+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_subtotals_without_grouping() {
+        let table = vec![vec!["a", "1"], vec!["b", "2"]]
+            .table()
+            .subtotals([1])
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++---+---+
+| a | 1 |
++---+---+
+| b | 2 |
++---+---+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let table = vec![vec!["EU", "10"], vec!["US", "20"], vec!["EU", "5"]]
+            .table()
+            .group_by(0)
+            .subtotals([1])
+            .separator(
+                Separator::builder()
+                    .column(Some(Default::default()))
+                    .group(Some(HorizontalLine::new('+', '+', '+', '=')))
+                    .build(),
+            )
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++---------------+
+| EU            |
+| EU       | 10 |
+| EU       | 5  |
+| Subtotal | 15 |
++==========+====+
+| US            |
+| US       | 20 |
+| Subtotal | 20 |
++----------+----+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_with_index() {
+        let table = vec![vec!["a"], vec!["b"], vec!["c"]]
+            .table()
+            .title(vec!["Letter"])
+            .with_index(1, Default::default())
+            .filter(|row| row.cells()[0].content() != "b")
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++---+--------+
+| # | Letter |
++---+--------+
+| 1 | a      |
++---+--------+
+| 3 | c      |
++---+--------+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_caption_and_headers() {
+        let table = vec![vec!["host-42", "12"]]
+            .table()
+            .title(vec!["Host", "Disk"])
+            .header(vec!["", "GiB"])
+            .caption("Disk usage")
+            .separator(
+                Separator::builder()
+                    .column(Some(Default::default()))
+                    .title(Some(HorizontalLine::new('+', '+', '+', '=')))
+                    .build(),
+            )
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++----------------+
+|   Disk usage   |
++=========+======+
+| Host    | Disk |
+|         | GiB  |
++=========+======+
+| host-42 | 12   |
++---------+------+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    /// Returns the lines of a table without trimming them (unlike `TableDisplay`)
+    fn lines(table: &TableStruct) -> Vec<String> {
+        let writer = BufferWriter::stdout(ColorChoice::Never);
+        let output = bytes(&table.buffers(&writer).unwrap());
+
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_caption_above() {
+        let table = vec![vec!["a", "b"]]
+            .table()
+            .caption("Letters")
+            .caption_position(CaptionPosition::Above);
+
+        assert_eq!(
+            vec![" Letters ", "+---+---+", "| a | b |", "+---+---+"],
+            lines(&table)
+        );
+    }
+
+    #[test]
+    fn test_long_caption() {
+        // Caption above the table can also span its borders
+        let positions = [
+            (CaptionPosition::Inside, "| a | b               |"),
+            (CaptionPosition::Above, "| a | b             |"),
+        ];
+
+        for (position, row) in positions {
+            let table = vec![vec!["a", "b"]]
+                .table()
+                .caption("Letters of alphabet")
+                .caption_position(position);
+
+            let lines = lines(&table);
+
+            assert!(lines.iter().all(|line| display_width(line) == row.len()));
+            assert_eq!(row, lines[lines.len() - 2]);
+        }
+    }
+
+    #[test]
+    fn test_row_headers() {
+        let table = vec![vec!["Q1", "10", "20"], vec!["Q2", "30", "40"]]
+            .table()
+            .title(vec!["", "EU", "US"])
+            .row_headers(true)
+            .separator(
+                Separator::builder()
+                    .column(Some(Default::default()))
+                    .title(Some(
+                        HorizontalLine::new('+', '+', '+', '=').stub_junction('#'),
+                    ))
+                    .stub(Some(VerticalLine::new('#')))
+                    .build(),
+            )
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++----+----+----+
+|    # EU | US |
++====#====+====+
+| Q1 # 10 | 20 |
+| Q2 # 30 | 40 |
++----+----+----+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_repeat_title() {
+        let table = vec![vec![1], vec![2], vec![3]]
+            .table()
+            .title(vec!["N"])
+            .repeat_title(2)
+            .color_choice(ColorChoice::Never);
+
+        let expected = "\
++---+
+| N |
++---+
+| 1 |
++---+
+| 2 |
++---+
+| N |
++---+
+| 3 |
++---+";
+
+        assert_eq!(expected, table.display().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_pages() {
+        let table = (1..=5)
+            .map(|i| vec![i])
+            .table()
+            .title(vec!["N"])
+            .separator(Separator::builder().build())
+            .color_choice(ColorChoice::Never);
+
+        let pages: Vec<String> = table
+            .pages(7)
+            .unwrap()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert_eq!(2, pages.len());
+        assert_eq!(
+            "+---+\n| N |\n| 1 |\n| 2 |\n| 3 |\n+---+\n Page 1 of 2",
+            pages[0]
+        );
+        assert_eq!("+---+\n| N |\n| 4 |\n| 5 |\n+---+\n Page 2 of 2", pages[1]);
+    }
+
+    #[test]
+    fn test_split_columns() {
+        let table = vec![vec!["a", "1", "2", "3"]]
+            .table()
+            .title(vec!["Name", "X", "Y", "Z"])
+            .separator(Separator::builder().build())
+            .split_columns(12, [0])
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+---------+\n| Name  X |\n| a     1 |\n+---------+\n\n\
+             +---------+\n| Name  Y |\n| a     2 |\n+---------+\n\n\
+             +---------+\n| Name  Z |\n| a     3 |\n+---------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_column_groups() {
+        let table = vec![vec!["1", "12.5", "1024"]]
+            .table()
+            .title(vec!["ID", "CPU", "Memory"])
+            .column_groups(vec!["".cell(), "Statistics (long)".cell().span(2)])
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+----+-------------------+\n\
+             |    | Statistics (long) |\n\
+             +----+------+------------+\n\
+             | ID | CPU  | Memory     |\n\
+             +----+------+------------+\n\
+             | 1  | 12.5 | 1024       |\n\
+             +----+------+------------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "title", feature = "derive"))]
+    fn test_skip_columns() {
+        let table = vec![vec!["1", "12.5", "1024"]]
+            .table()
+            .title(vec!["ID", "CPU", "Memory"])
+            .column_groups(vec!["".cell(), "Stats".cell().span(2)])
+            .skip_columns(&[1])
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+----+--------+\n\
+             |    | Stats  |\n\
+             +----+--------+\n\
+             | ID | Memory |\n\
+             +----+--------+\n\
+             | 1  | 1024   |\n\
+             +----+--------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_null_placeholder() {
+        let rows = || {
+            vec![
+                vec!["a".cell(), CellStruct::null()],
+                vec!["b".cell(), 1.cell()],
+            ]
+        };
+
+        let blank = rows().table().color_choice(ColorChoice::Never);
+        let placeholder = rows()
+            .table()
+            .null_placeholder("n/a")
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "| a |   |",
+            blank.display().unwrap().to_string().lines().nth(1).unwrap()
+        );
+        assert_eq!(
+            "| a | n/a |",
+            placeholder
+                .display()
+                .unwrap()
+                .to_string()
+                .lines()
+                .nth(1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_nested_table() {
+        let nested = || {
+            vec![vec!["1", "2"]].table().separator(
+                Separator::builder()
+                    .column(Some(Default::default()))
+                    .build(),
+            )
+        };
+
+        let table = vec![
+            vec!["Nested".cell(), nested().cell()],
+            vec!["Merged".cell(), nested().cell().merge_border(true)],
+        ]
+        .table()
+        .separator(nested().format.separator)
+        .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+--------+-----------+\n\
+             | Nested | +---+---+ |\n\
+             |        | | 1 | 2 | |\n\
+             |        | +---+---+ |\n\
+             | Merged | 1 | 2     |\n\
+             +--------+-----------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_nested_split_table() {
+        let nested = vec![vec!["id", "alpha", "beta"]]
+            .table()
+            .split_columns(14, [0]);
+
+        let table = vec![vec!["Split".cell(), nested.cell()]]
+            .table()
+            .color_choice(ColorChoice::Never);
+
+        // Slices are stacked without blank lines between them and narrower slices are padded
+        assert_eq!(
+            "+-------+----------------+\n\
+             | Split | +----+-------+ |\n\
+             |       | | id | alpha | |\n\
+             |       | +----+-------+ |\n\
+             |       | +----+------+  |\n\
+             |       | | id | beta |  |\n\
+             |       | +----+------+  |\n\
+             +-------+----------------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_lazy_cells_shrink_to_max_width() {
+        let path = |width: usize| {
+            let path = "/usr/local/share/doc";
+            let elided = match path.len() > width {
+                true => format!("…{}", &path[path.len() - width + 1..]),
+                false => path.to_string(),
+            };
+
+            vec![elided]
+        };
+
+        let table = vec![vec!["docs".cell(), CellStruct::lazy(20, path).min_width(8)]]
+            .table()
+            .max_width(20)
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+------+-----------+\n\
+             | docs | …hare/doc |\n\
+             +------+-----------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_print_pager() {
+        /// Removes the file at given path when dropped (even if the test panics)
+        struct TempFile(std::path::PathBuf);
+
+        impl Drop for TempFile {
+            fn drop(&mut self) {
+                let _ = std::fs::remove_file(&self.0);
+            }
+        }
+
+        let output = TempFile(
+            std::env::temp_dir().join(format!("cli-table pager {}.out", std::process::id())),
+        );
+
+        let table = vec![vec!["Hello"]].table().color_choice(ColorChoice::Never);
+
+        table
+            .print_pager(&format!("cat > '{}'", output.0.display()), None)
+            .unwrap();
+
+        let paged = std::fs::read_to_string(&output.0).unwrap();
+
+        assert_eq!("+-------+\n| Hello |\n+-------+\n", paged);
+    }
 }
@@ -8,6 +8,30 @@ use crate::{Row, RowStruct, Table, TableStruct};
 pub trait Title {
     /// Returns title row of a struct
     fn title() -> RowStruct;
+
+    /// Customizes a table created using [`WithTitle::with_title`] (e.g., adds an index column)
+    ///
+    /// Implementation detail of `#[derive(Table)]` (not part of public API).
+    #[doc(hidden)]
+    fn customize_table(table: TableStruct) -> TableStruct {
+        table
+    }
+
+    /// Whether [`Title::skipped_columns`] can skip any columns (i.e., it is implemented for the struct)
+    ///
+    /// Implementation detail of `#[derive(Table)]` (not part of public API).
+    #[doc(hidden)]
+    const SKIPS_COLUMNS: bool = false;
+
+    /// Returns the columns to be skipped in a table created using [`WithTitle::with_title`] from given rows (e.g.,
+    /// columns which are empty in all the rows)
+    ///
+    /// This is only called when [`Title::SKIPS_COLUMNS`] is `true`. Implementation detail of `#[derive(Table)]` (not
+    /// part of public API).
+    #[doc(hidden)]
+    fn skipped_columns(_rows: &[&Self]) -> Vec<usize> {
+        Vec::new()
+    }
 }
 
 /// Trait for creating a table with titles at the top
@@ -27,8 +51,88 @@ where
     &'a R: Row,
 {
     fn with_title(self) -> TableStruct {
-        let table = self.table();
         let title = R::title();
-        table.title(title)
+
+        if !R::SKIPS_COLUMNS {
+            return R::customize_table(self.table().title(title));
+        }
+
+        let rows: Vec<&R> = self.into_iter().collect();
+        let skipped = R::skipped_columns(&rows);
+
+        R::customize_table(rows.table().title(title)).skip_columns(&skipped)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+
+    use crate::ColorChoice;
+
+    #[derive(crate::Table)]
+    #[table(crate = "crate")]
+    struct Usage {
+        #[table(title = "CPU")]
+        cpu: u8,
+        #[table(title = "GPU", skip_if = "Option::is_none")]
+        gpu: Option<u8>,
+    }
+
+    #[derive(crate::Table)]
+    #[table(crate = "crate")]
+    struct Process {
+        #[table(title = "Name", skip_if = "|name: &&str| name.is_empty()")]
+        name: &'static str,
+        #[table(title = "ID")]
+        id: u32,
+        #[table(flatten)]
+        usage: Usage,
+    }
+
+    #[derive(crate::Table)]
+    #[table(crate = "crate", index = 10)]
+    struct User {
+        #[table(title = "Name")]
+        name: &'static str,
+    }
+
+    #[test]
+    fn test_index() {
+        let users = vec![User { name: "Ada" }, User { name: "Alan" }];
+
+        let table = users
+            .with_title()
+            .filter(|row| row.cells()[0].content() != "Ada")
+            .color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+----+------+\n\
+             |  # | Name |\n\
+             +----+------+\n\
+             | 11 | Alan |\n\
+             +----+------+",
+            table.display().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_skip_flattened_columns() {
+        let processes = vec![Process {
+            name: "",
+            id: 1,
+            usage: Usage { cpu: 12, gpu: None },
+        }];
+
+        let table = processes.with_title().color_choice(ColorChoice::Never);
+
+        assert_eq!(
+            "+----+-----+\n\
+             | ID | CPU |\n\
+             +----+-----+\n\
+             | 1  | 12  |\n\
+             +----+-----+",
+            table.display().unwrap().to_string()
+        );
     }
 }
@@ -0,0 +1,257 @@
+use crate::{Row, RowStruct, Table, TableStruct};
+
+/// Trait for types containing children of the same type (e.g., directories or dependency trees)
+pub trait Tree: Sized {
+    /// Returns the children of current node
+    fn children(&self) -> &[Self];
+}
+
+/// A row of a table along with its children
+pub struct TreeNode {
+    row: RowStruct,
+    children: Vec<TreeNode>,
+    collapsed: bool,
+}
+
+impl TreeNode {
+    /// Creates a new tree node from a row
+    pub fn new<R: Row>(row: R) -> Self {
+        Self {
+            row: row.row(),
+            children: Vec::new(),
+            collapsed: false,
+        }
+    }
+
+    /// Creates a tree node from a type implementing `Tree` trait
+    pub fn from_tree<T>(tree: &T) -> Self
+    where
+        T: Tree,
+        for<'a> &'a T: Row,
+    {
+        Self::new(tree).children(tree.children().iter().map(Self::from_tree))
+    }
+
+    /// Used to add a child node
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Used to add multiple child nodes
+    pub fn children<I: IntoIterator<Item = TreeNode>>(mut self, children: I) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Used to hide all the descendants of current node
+    ///
+    /// First cell of a collapsed node is suffixed with the number of hidden descendants, e.g., `(+3)`.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    fn descendants(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| 1 + child.descendants())
+            .sum()
+    }
+
+    /// Appends rows of current node and its visible descendants with indentation guides in first column
+    fn flatten(mut self, guides: &mut Vec<bool>, rows: &mut Vec<RowStruct>) {
+        if self.collapsed && !self.children.is_empty() {
+            let descendants = self.descendants();
+
+            if let Some(line) = self
+                .row
+                .cells
+                .first_mut()
+                .and_then(|cell| cell.data.first_mut())
+            {
+                line.push_str(&format!(" (+{})", descendants));
+            }
+
+            self.children.clear();
+        }
+
+        if let Some(cell) = self.row.cells.first_mut() {
+            let (is_last, ancestors) = match guides.split_last() {
+                Some((is_last, ancestors)) => (Some(*is_last), ancestors),
+                None => (None, &[][..]),
+            };
+
+            let indent: String = ancestors
+                .iter()
+                .map(|is_last| if *is_last { "   " } else { "│  " })
+                .collect();
+
+            for (i, line) in cell.data.iter_mut().enumerate() {
+                let guide = match (is_last, i) {
+                    (None, _) => "",
+                    (Some(false), 0) => "├─ ",
+                    (Some(true), 0) => "└─ ",
+                    (Some(false), _) => "│  ",
+                    (Some(true), _) => "   ",
+                };
+
+                *line = format!("{}{}{}", indent, guide, line);
+            }
+        }
+
+        rows.push(self.row);
+
+        let num_children = self.children.len();
+
+        for (i, child) in self.children.into_iter().enumerate() {
+            guides.push(i + 1 == num_children);
+            child.flatten(guides, rows);
+            guides.pop();
+        }
+    }
+}
+
+/// Builder for a table whose rows are arranged as a tree
+///
+/// First column of each row is prefixed with indentation guides (`├─`, `└─` and `│`) based on its depth in the tree.
+#[derive(Default)]
+pub struct TreeTable {
+    nodes: Vec<TreeNode>,
+}
+
+impl TreeTable {
+    /// Creates a new tree table
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a tree table from a collection of types implementing `Tree` trait
+    pub fn from_trees<'a, I, T>(trees: I) -> Self
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: Tree + 'a,
+        for<'b> &'b T: Row,
+    {
+        Self {
+            nodes: trees.into_iter().map(TreeNode::from_tree).collect(),
+        }
+    }
+
+    /// Used to add a root node
+    pub fn node(mut self, node: TreeNode) -> Self {
+        self.nodes.push(node);
+        self
+    }
+}
+
+impl Table for TreeTable {
+    fn table(self) -> TableStruct {
+        let mut rows = Vec::new();
+
+        for node in self.nodes {
+            node.flatten(&mut Vec::new(), &mut rows);
+        }
+
+        rows.table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ColorChoice;
+
+    /// Returns the contents of the rows of a tree table
+    fn lines(table: TreeTable) -> Vec<String> {
+        table
+            .table()
+            .separator(Default::default())
+            .color_choice(ColorChoice::Never)
+            .display()
+            .unwrap()
+            .to_string()
+            .lines()
+            .skip(1)
+            .step_by(2)
+            .map(|line| line.trim_matches(['|', ' ']).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_tree_guides() {
+        let table = TreeTable::new().node(
+            TreeNode::new(["cli-table"])
+                .child(TreeNode::new(["termcolor"]))
+                .child(
+                    TreeNode::new(["csv"])
+                        .child(TreeNode::new(["csv-core"]))
+                        .child(TreeNode::new(["serde"]).child(TreeNode::new(["serde_derive"])))
+                        .child(TreeNode::new(["itoa"]).collapsed(true)),
+                )
+                .child(TreeNode::new(["unicode-width"]).collapsed(true)),
+        );
+
+        assert_eq!(
+            vec![
+                "cli-table",
+                "├─ termcolor",
+                "├─ csv",
+                "│  ├─ csv-core",
+                "│  ├─ serde",
+                "│  │  └─ serde_derive",
+                "│  └─ itoa",
+                "└─ unicode-width",
+            ],
+            lines(table)
+        );
+    }
+
+    #[test]
+    fn test_collapsed() {
+        let table = TreeTable::new().node(
+            TreeNode::new(["cli-table"])
+                .child(
+                    TreeNode::new(["csv"])
+                        .child(TreeNode::new(["csv-core"]))
+                        .child(TreeNode::new(["serde"]).child(TreeNode::new(["serde_derive"])))
+                        .collapsed(true),
+                )
+                .child(TreeNode::new(["termcolor"])),
+        );
+
+        assert_eq!(
+            vec!["cli-table", "├─ csv (+3)", "└─ termcolor"],
+            lines(table)
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_children() {
+        #[derive(crate::Table)]
+        #[table(crate = "crate")]
+        struct Entry {
+            #[table(title = "Name")]
+            name: &'static str,
+            #[table(children)]
+            children: Vec<Entry>,
+        }
+
+        let entry = |name, children| Entry { name, children };
+
+        let root = entry(
+            "src",
+            vec![
+                entry("bin", vec![entry("main.rs", Vec::new())]),
+                entry("lib.rs", Vec::new()),
+            ],
+        );
+
+        assert_eq!(
+            vec!["src", "├─ bin", "│  └─ main.rs", "└─ lib.rs"],
+            lines(TreeTable::from_trees([&root]))
+        );
+    }
+}
@@ -4,6 +4,7 @@ use termcolor::{ColorSpec, WriteColor};
 
 use crate::{
     buffers::Buffers,
+    row::RowStruct,
     table::{Dimension as TableDimension, HorizontalLine, TableFormat, VerticalLine},
 };
 
@@ -95,22 +96,47 @@ pub(crate) fn print_horizontal_line(
     table_dimension: &TableDimension,
     table_format: &TableFormat,
     color_spec: &ColorSpec,
+) -> Result<()> {
+    print_line_between(
+        buffers,
+        line,
+        table_dimension,
+        table_format,
+        color_spec,
+        &[],
+    )
+}
+
+/// Prints a horizontal line adjacent to given rows, with junctions only where at least one of the rows is separated
+/// (i.e., not below or above a cell spanning multiple columns)
+pub(crate) fn print_line_between(
+    buffers: &mut Buffers<'_>,
+    line: Option<&HorizontalLine>,
+    table_dimension: &TableDimension,
+    table_format: &TableFormat,
+    color_spec: &ColorSpec,
+    rows: &[&RowStruct],
 ) -> Result<()> {
     if let Some(line) = line {
         if table_format.border.left.is_some() {
             print_char(buffers, line.left_end, color_spec)?;
         }
 
-        let mut widths = table_dimension.widths.iter().peekable();
+        let mut widths = table_dimension.widths.iter().enumerate().peekable();
 
-        while let Some(width) = widths.next() {
+        while let Some((column, width)) = widths.next() {
             let s = std::iter::repeat_n(line.filler, width + 2).collect::<String>();
             print_str(buffers, &s, color_spec)?;
 
             match widths.peek() {
                 Some(_) => {
-                    if table_format.separator.column.is_some() {
-                        print_char(buffers, line.junction, color_spec)?
+                    if let Some(junction) = table_format.junction(line, column) {
+                        let separated =
+                            rows.is_empty() || rows.iter().any(|row| row.is_separated(column));
+
+                        let junction = if separated { junction } else { line.filler };
+
+                        print_char(buffers, junction, color_spec)?
                     }
                 }
                 None => {
@@ -129,6 +155,20 @@ pub(crate) fn print_horizontal_line(
     Ok(())
 }
 
+/// Returns the line as it is when `junctions` is `true`, otherwise returns the line without junctions
+pub(crate) fn with_junctions(
+    line: Option<&HorizontalLine>,
+    junctions: bool,
+) -> Option<HorizontalLine> {
+    line.map(|line| {
+        if junctions {
+            *line
+        } else {
+            line.without_junctions()
+        }
+    })
+}
+
 pub(crate) fn print_vertical_line(
     buffers: &mut Buffers<'_>,
     line: Option<&VerticalLine>,
@@ -0,0 +1,531 @@
+use std::borrow::Cow;
+
+use crate::{
+    cell::{Cell, CellStruct, Justify},
+    fmt::group_thousands,
+    row::RowStruct,
+    rule::Rule,
+    style::{Style, StyleStruct},
+    utils::display_width,
+};
+
+/// Predicate used for filtering rows of a table
+pub(crate) type RowFilter = Box<dyn Fn(&RowStruct) -> bool + Send + Sync>;
+
+/// Rows of a table to keep when it is too long to be printed completely
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Elision {
+    /// Keep first `n` rows
+    Head(usize),
+    /// Keep last `n` rows
+    Tail(usize),
+    /// Keep first `n` and last `n` rows
+    Middle(usize),
+}
+
+/// Configuration for grouping rows of a table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Grouping {
+    /// Column used for grouping the rows
+    pub(crate) column: usize,
+}
+
+/// Configuration for the index column of a table
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Index {
+    /// Index of the first row of the table
+    pub(crate) start: usize,
+    /// Style of the cells in index column
+    pub(crate) style: StyleStruct,
+}
+
+/// Configuration for selecting and arranging the rows of a table before printing
+#[derive(Default)]
+pub(crate) struct View {
+    pub(crate) filters: Vec<RowFilter>,
+    pub(crate) elision: Option<Elision>,
+    pub(crate) grouping: Option<Grouping>,
+    /// Columns for which subtotals are computed for each group (only when rows are grouped)
+    pub(crate) subtotals: Vec<usize>,
+    pub(crate) index: Option<Index>,
+    pub(crate) row_headers: bool,
+    pub(crate) rules: Vec<Rule>,
+    pub(crate) null_placeholder: Option<CellStruct>,
+}
+
+/// An entry in the body (i.e., everything below title) of a table
+pub(crate) enum BodyRow<'a> {
+    /// A row of the table along with its position in the table (`None` for rows computed while printing the table,
+    /// e.g., subtotals)
+    Row(Option<usize>, Cow<'a, RowStruct>),
+    /// A single cell spanning the full width of the table
+    Span(CellStruct),
+    /// A single cell spanning the full width of the table which starts a new group of rows
+    Section(CellStruct),
+    /// Repetition of header rows of the table
+    Headers,
+}
+
+impl View {
+    /// Returns the header rows (i.e., column groups and title rows followed by other header rows) after adding cells for extra columns
+    /// (e.g., index)
+    pub(crate) fn headers<'a>(
+        &self,
+        groups: Option<&'a RowStruct>,
+        title: Option<&'a RowStruct>,
+        headers: &'a [RowStruct],
+    ) -> Vec<Cow<'a, RowStruct>> {
+        let mut headers: Vec<Cow<'a, RowStruct>> = groups
+            .into_iter()
+            .chain(title)
+            .chain(headers)
+            .map(Cow::Borrowed)
+            .collect();
+
+        if let Some(index) = self.index {
+            // Index column is titled in the title row (or the first header row when there is no title row)
+            let titled = usize::from(groups.is_some() && title.is_some());
+
+            for (i, header) in headers.iter_mut().enumerate() {
+                let mut cell = if i == titled { "#" } else { "" }
+                    .cell()
+                    .justify(Justify::Right);
+                cell.style = index.style.bold(true);
+
+                header.to_mut().cells.insert(0, cell);
+            }
+        }
+
+        headers
+    }
+
+    /// Selects and arranges the rows to be displayed after applying filters, grouping and elision
+    ///
+    /// Conditional formatting rules are applied to the selected rows.
+    ///
+    /// Lines of cells justified on a character (`Justify::On`) are also padded so that the character lines up in
+    /// each column.
+    pub(crate) fn body<'a>(&self, rows: &'a [RowStruct]) -> Vec<BodyRow<'a>> {
+        let rows: Vec<(usize, &RowStruct)> = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| self.filters.iter().all(|filter| filter(row)))
+            .collect();
+
+        // Rules consider all the rows which satisfy the filters (including the rows hidden by elision)
+        let filtered: Vec<&RowStruct> = rows.iter().map(|(_, row)| *row).collect();
+
+        let groups = match self.grouping {
+            Some(ref grouping) => group(rows, grouping.column),
+            None => vec![(None, rows)],
+        };
+
+        let total = groups.iter().map(|(_, rows)| rows.len()).sum::<usize>();
+
+        let (head, tail) = match self.elision {
+            None => (total, 0),
+            Some(Elision::Head(n)) => (n.min(total), 0),
+            Some(Elision::Tail(n)) => (0, n.min(total)),
+            Some(Elision::Middle(n)) => (n.min(total), n.min(total.saturating_sub(n))),
+        };
+
+        let hidden = total - head - tail;
+        let is_visible = |position: usize| position < head || position >= total - tail;
+
+        // Groups in which all the rows are hidden by elision
+        let mut start = 0;
+        let hidden_groups = groups
+            .iter()
+            .filter(|(_, rows)| {
+                let group_start = start;
+                start += rows.len();
+                !(group_start..start).any(is_visible)
+            })
+            .count();
+
+        let mut marker = (hidden > 0).then(|| more_rows(hidden, hidden_groups));
+
+        let mut body = Vec::new();
+        let mut position = 0;
+
+        for (key, rows) in groups.iter() {
+            // When rows are grouped, marker is placed at the first group boundary after the start of hidden rows so
+            // that it never splits a group (or separates it from its subtotal)
+            if self.grouping.is_some() && position >= head {
+                body.extend(marker.take().map(BodyRow::Span));
+            }
+
+            let mut section = key.as_ref().map(|key| key.clone().cell().bold(true));
+
+            for row in rows.iter() {
+                if self.grouping.is_none() && position == head {
+                    body.extend(marker.take().map(BodyRow::Span));
+                }
+
+                if is_visible(position) {
+                    if let Some(section) = section.take() {
+                        body.push(BodyRow::Section(section));
+                    }
+
+                    body.push(BodyRow::Row(Some(row.0), Cow::Borrowed(row.1)));
+                }
+
+                position += 1;
+            }
+
+            if self.grouping.is_some() && section.is_none() && !self.subtotals.is_empty() {
+                let subtotal = subtotal(rows, &self.subtotals);
+                body.push(BodyRow::Row(None, Cow::Owned(subtotal)));
+            }
+        }
+
+        body.extend(marker.map(BodyRow::Span));
+
+        if self.row_headers {
+            for body_row in body.iter_mut() {
+                if let BodyRow::Row(_, row) = body_row
+                    && let Some(cell) = row.to_mut().cells.first_mut()
+                {
+                    cell.style = cell.style.bold(true);
+                }
+            }
+        }
+
+        if let Some(ref placeholder) = self.null_placeholder {
+            for body_row in body.iter_mut() {
+                if let BodyRow::Row(_, row) = body_row
+                    && row.cells.iter().any(|cell| cell.null)
+                {
+                    for cell in row.to_mut().cells.iter_mut().filter(|cell| cell.null) {
+                        cell.data = placeholder.data.clone();
+                        cell.style = cell.style.overlay(placeholder.style);
+                    }
+                }
+            }
+        }
+
+        for rule in self.rules.iter() {
+            rule.apply(&mut body, &filtered);
+        }
+
+        align_on(&mut body);
+
+        if let Some(index) = self.index {
+            for body_row in body.iter_mut() {
+                if let BodyRow::Row(position, row) = body_row {
+                    let mut cell = match position {
+                        Some(position) => (index.start + *position).cell(),
+                        None => "".cell(),
+                    }
+                    .justify(Justify::Right);
+                    cell.style = index.style;
+
+                    row.to_mut().cells.insert(0, cell);
+                }
+            }
+        }
+
+        body
+    }
+}
+
+impl BodyRow<'_> {
+    /// Returns the cell if this entry is a spanning cell
+    pub(crate) fn span(&self) -> Option<&CellStruct> {
+        match self {
+            BodyRow::Span(cell) | BodyRow::Section(cell) => Some(cell),
+            BodyRow::Row(..) | BodyRow::Headers => None,
+        }
+    }
+}
+
+/// Rows in a group along with their positions in the table and the value of grouping column
+type Group<'a> = (Option<String>, Vec<(usize, &'a RowStruct)>);
+
+/// Groups rows by the contents of given column, in the order of first appearance of each group
+fn group(rows: Vec<(usize, &RowStruct)>, column: usize) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group<'_>> = Vec::new();
+
+    for row in rows {
+        let key = row
+            .1
+            .cells
+            .get(column)
+            .map(CellStruct::content)
+            .unwrap_or_default();
+
+        match groups
+            .iter_mut()
+            .find(|(group_key, _)| group_key.as_deref() == Some(key.as_str()))
+        {
+            Some((_, group_rows)) => group_rows.push(row),
+            None => groups.push((Some(key), vec![row])),
+        }
+    }
+
+    groups
+}
+
+/// Computes a row containing sums of given columns of rows
+fn subtotal(rows: &[(usize, &RowStruct)], columns: &[usize]) -> RowStruct {
+    let num_columns = rows
+        .iter()
+        .map(|(_, row)| row.cells.len())
+        .max()
+        .unwrap_or(0);
+    let label_column = (0..num_columns).find(|column| !columns.contains(column));
+
+    let cells = (0..num_columns)
+        .map(|column| {
+            if Some(column) == label_column {
+                return "Subtotal".cell().bold(true);
+            }
+
+            if !columns.contains(&column) {
+                return "".cell();
+            }
+
+            let cells: Vec<&CellStruct> = rows
+                .iter()
+                .filter_map(|(_, row)| row.cells.get(column))
+                .collect();
+            let justify = cells
+                .first()
+                .map(|cell| cell.format.justify)
+                .unwrap_or_default();
+
+            sum(&cells).cell().bold(true).justify(justify)
+        })
+        .collect();
+
+    RowStruct { cells }
+}
+
+/// Sums the numeric contents of given cells, ignoring the cells which are not numeric
+fn sum(cells: &[&CellStruct]) -> String {
+    let mut total = 0.0;
+    let mut precision = 0;
+    let mut numeric = false;
+
+    for cell in cells {
+        let content = cell.content().trim().replace([',', '_'], "");
+
+        if let Ok(value) = content.parse::<f64>() {
+            total += value;
+            numeric = true;
+
+            if let Some((_, fraction)) = content.split_once('.') {
+                precision = std::cmp::max(fraction.len(), precision);
+            }
+        }
+    }
+
+    if numeric {
+        format!("{:.*}", precision, total)
+    } else {
+        String::new()
+    }
+}
+
+/// Pads the lines of cells justified on a character so that the first occurrences of the character line up in each
+/// column
+///
+/// Lazily rendered cells and cells containing nested tables are not padded as their lines are not known in advance.
+fn align_on(body: &mut [BodyRow<'_>]) {
+    let aligned_on = |cell: &CellStruct| match cell.format.justify {
+        Justify::On(c) if cell.lazy.is_none() && cell.table.is_none() => Some(c),
+        _ => None,
+    };
+
+    // Maximum widths of the parts of lines before and after the alignment character in each column
+    let mut widths: Vec<(usize, usize)> = Vec::new();
+
+    for body_row in body.iter() {
+        if let BodyRow::Row(_, row) = body_row {
+            for (column, cell) in row.cells.iter().enumerate() {
+                if let Some(c) = aligned_on(cell) {
+                    if widths.len() <= column {
+                        widths.resize(column + 1, (0, 0));
+                    }
+
+                    for line in cell.data.iter() {
+                        let (before, after) = split_width(line, c);
+
+                        widths[column].0 = std::cmp::max(before, widths[column].0);
+                        widths[column].1 = std::cmp::max(after, widths[column].1);
+                    }
+                }
+            }
+        }
+    }
+
+    if widths.is_empty() {
+        return;
+    }
+
+    for body_row in body.iter_mut() {
+        if let BodyRow::Row(_, row) = body_row
+            && row.cells.iter().any(|cell| aligned_on(cell).is_some())
+        {
+            for (column, cell) in row.to_mut().cells.iter_mut().enumerate() {
+                if let Some(c) = aligned_on(cell) {
+                    let (max_before, max_after) = widths[column];
+
+                    for line in cell.data.iter_mut() {
+                        let (before, after) = split_width(line, c);
+
+                        *line = format!(
+                            "{}{}{}",
+                            " ".repeat(max_before - before),
+                            line,
+                            " ".repeat(max_after - after)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the display widths of the parts of a line before and after (including) the first occurrence of given
+/// character
+fn split_width(line: &str, c: char) -> (usize, usize) {
+    match line.find(c) {
+        Some(i) => (display_width(&line[..i]), display_width(&line[i..])),
+        None => (display_width(line), 0),
+    }
+}
+
+/// Creates the marker cell displayed in place of hidden rows (and groups in which all the rows are hidden)
+fn more_rows(count: usize, groups: usize) -> CellStruct {
+    let noun = if count == 1 { "row" } else { "rows" };
+
+    let groups = match groups {
+        0 => String::new(),
+        1 => " (1 more group)".to_string(),
+        groups => format!(
+            " ({} more groups)",
+            group_thousands(&groups.to_string(), ' ')
+        ),
+    };
+
+    format!(
+        "… {} more {}{} …",
+        group_thousands(&count.to_string(), ' '),
+        noun,
+        groups
+    )
+    .cell()
+    .justify(Justify::Center)
+    .dimmed(true)
+    .italic(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::row::Row;
+
+    #[test]
+    fn test_align_on() {
+        let rows: Vec<RowStruct> = ["3.5", "12.125", "100"]
+            .iter()
+            .map(|value| vec![value.cell().justify(Justify::On('.'))].row())
+            .collect();
+        let body = View::default().body(&rows);
+
+        let lines: Vec<String> = body
+            .iter()
+            .filter_map(|body_row| match body_row {
+                BodyRow::Row(_, row) => Some(row.cells[0].content()),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(vec!["  3.5  ", " 12.125", "100    "], lines);
+    }
+
+    #[test]
+    fn test_select_middle() {
+        let rows: Vec<RowStruct> = (0..10).map(|i| vec![i].row()).collect();
+        let view = View {
+            elision: Some(Elision::Middle(2)),
+            ..Default::default()
+        };
+        let body = view.body(&rows);
+
+        assert_eq!(5, body.len());
+        assert!(matches!(body[2], BodyRow::Span(_)));
+    }
+
+    #[test]
+    fn test_group_subtotals() {
+        let rows: Vec<RowStruct> = vec![
+            vec!["EU", "1.5"].row(),
+            vec!["US", "3"].row(),
+            vec!["EU", "2.25"].row(),
+        ];
+        let view = View {
+            grouping: Some(Grouping { column: 0 }),
+            subtotals: vec![1],
+            ..Default::default()
+        };
+        let body = view.body(&rows);
+
+        assert_eq!(7, body.len());
+        assert!(matches!(body[0], BodyRow::Section(_)));
+
+        let BodyRow::Row(None, ref subtotal) = body[3] else {
+            panic!("expected a subtotal row");
+        };
+        assert_eq!("Subtotal", subtotal.cells[0].content());
+        assert_eq!("3.75", subtotal.cells[1].content());
+    }
+
+    #[test]
+    fn test_group_subtotals_with_elision() {
+        let rows: Vec<RowStruct> = vec![
+            vec!["EU", "1"].row(),
+            vec!["EU", "2"].row(),
+            vec!["EU", "3"].row(),
+            vec!["US", "4"].row(),
+            vec!["US", "5"].row(),
+            vec!["CN", "6"].row(),
+            vec!["CN", "7"].row(),
+            vec!["CN", "8"].row(),
+        ];
+        let view = View {
+            grouping: Some(Grouping { column: 0 }),
+            subtotals: vec![1],
+            elision: Some(Elision::Middle(2)),
+            ..Default::default()
+        };
+        let body = view.body(&rows);
+
+        let lines: Vec<String> = body
+            .iter()
+            .map(|body_row| match body_row {
+                BodyRow::Row(_, row) => row.cells[1].content(),
+                BodyRow::Span(cell) | BodyRow::Section(cell) => cell.content(),
+                BodyRow::Headers => String::new(),
+            })
+            .collect();
+
+        // Marker follows the subtotal of the first group and `US` group is hidden completely
+        assert_eq!(
+            vec![
+                "EU",
+                "1",
+                "2",
+                "6",
+                "… 4 more rows (1 more group) …",
+                "CN",
+                "7",
+                "8",
+                "21",
+            ],
+            lines
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Value of `display_fn` should be a valid expression
+use cli_table::Table;
+
+#[derive(Table)]
+struct Test {
+    #[table(display_fn = "fn")]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//! Value of `format` should be a valid format string
+use cli_table::Table;
+
+#[derive(Table)]
+struct Test {
+    #[table(format = "{:.2")]
+    a: f64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//! `format` and `display_fn` cannot be used together on a field
+use cli_table::Table;
+
+#[derive(Table)]
+struct Test {
+    #[table(display_fn = "ToString::to_string", format = "{:.2}")]
+    a: f64,
+}
+
+fn main() {}
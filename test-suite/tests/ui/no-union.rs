@@ -0,0 +1,10 @@
+//! `Table` derive macro is not allowed on unions
+use cli_table::Table;
+
+#[derive(Table)]
+union Test {
+    a: u32,
+    b: f32,
+}
+
+fn main () {}